@@ -0,0 +1,357 @@
+//! Derive macros for `faker_rand` generators.
+//!
+//! This crate is an implementation detail of `faker_rand`. Rather than
+//! depending on it directly, enable `faker_rand`'s `derive` feature, and use
+//! the derive macros re-exported from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Fields, Ident, LitInt, LitStr, Token, Type};
+
+/// Derives a generator from a set of format strings and sub-generators.
+///
+/// This is an alternative to `faker_impl_from_templates!`, and generates
+/// exactly the same implementations. The type must be a newtype around a
+/// [`String`], and each of its `#[template(...)]` attributes is one template
+/// pattern. A template pattern is written the same way as in
+/// `faker_impl_from_templates!`, including its optional `WEIGHT =>` prefix,
+/// `repeat(MIN..=MAX, SEP, T)` arguments, and `let NAME = T;` bindings, except
+/// that a trailing comma is allowed and no final semicolon is needed.
+///
+/// ```
+/// use faker_rand::FakeTemplate;
+/// use faker_rand::lorem::Word;
+/// use faker_rand::util::AsciiDigit;
+///
+/// #[derive(FakeTemplate)]
+/// #[template(3 => "{}.{}", AsciiDigit, Word)]
+/// #[template("{} ~~~ {}", Word, AsciiDigit)]
+/// struct Demo(String);
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("5.adipisci", rng.gen::<Demo>().to_string());
+/// assert_eq!("4.dolorem", rng.gen::<Demo>().to_string());
+/// assert_eq!("sequi ~~~ 5", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// Bindings go at the start of the attribute:
+///
+/// ```
+/// use faker_rand::FakeTemplate;
+/// use faker_rand::en_us::names::FirstName;
+/// use faker_rand::util::ToAsciiLowercase;
+///
+/// #[derive(FakeTemplate)]
+/// #[template(let first = FirstName; "{first} ({})", ToAsciiLowercase<first>)]
+/// struct Demo(String);
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("Joseph (joseph)", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// Mistakes in a template pattern are reported at the token that caused them:
+///
+/// ```compile_fail
+/// use faker_rand::FakeTemplate;
+/// use faker_rand::lorem::Word;
+///
+/// #[derive(FakeTemplate)]
+/// #[template(Word, "{}")] // error: expected a format string literal here
+/// struct Demo(String);
+/// ```
+#[proc_macro_derive(FakeTemplate, attributes(template))]
+pub fn derive_fake_template(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_fake_template(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives a generator from a file containing a list of words.
+///
+/// This is an alternative to `faker_impl_from_file!`, and generates exactly the
+/// same implementations. The type must be a newtype around a [`String`] or a
+/// `&'static str`, and must have exactly one `#[file("...")]` attribute. As
+/// with `faker_impl_from_file!`, the path is relative to the file the type is
+/// declared in, and each line of the file may end in a tab and a weight.
+///
+/// ```
+/// use faker_rand::FakeWordFile;
+///
+/// // data/sizes contains "small", "medium", and "large", one per line.
+/// #[derive(FakeWordFile)]
+/// #[file("data/sizes")]
+/// struct Size(&'static str);
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("large", rng.gen::<Size>().to_string());
+/// assert_eq!("medium", rng.gen::<Size>().to_string());
+/// ```
+///
+/// ```compile_fail
+/// use faker_rand::FakeWordFile;
+///
+/// #[derive(FakeWordFile)]
+/// #[file(data / sizes)] // error: expected a file path string literal here
+/// struct Size(&'static str);
+/// ```
+#[proc_macro_derive(FakeWordFile, attributes(file))]
+pub fn derive_fake_word_file(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_fake_word_file(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_fake_template(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    check_newtype(input, "FakeTemplate")?;
+
+    let mut templates = Vec::new();
+    for attr in attrs_named(&input.attrs, "template") {
+        templates.push(attr.parse_args::<Template>()?);
+    }
+
+    if templates.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "FakeTemplate requires at least one #[template(...)] attribute",
+        ));
+    }
+
+    if templates.iter().all(Template::is_never_chosen) {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "at least one template must have a non-zero weight",
+        ));
+    }
+
+    let name = &input.ident;
+    Ok(quote! {
+        ::faker_rand::faker_impl_from_templates! {
+            #name;
+
+            #(#templates)*
+        }
+    })
+}
+
+fn expand_fake_word_file(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    check_newtype(input, "FakeWordFile")?;
+
+    let mut attrs = attrs_named(&input.attrs, "file");
+    let file = match (attrs.next(), attrs.next()) {
+        (Some(attr), None) => attr.parse_args_with(|input: ParseStream| {
+            if !input.peek(LitStr) {
+                return Err(input.error("expected a file path string literal here"));
+            }
+
+            input.parse::<LitStr>()
+        })?,
+        (None, _) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FakeWordFile requires a #[file(\"...\")] attribute",
+            ))
+        }
+        (Some(_), Some(attr)) => {
+            return Err(Error::new_spanned(
+                attr,
+                "FakeWordFile accepts only one #[file(\"...\")] attribute",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    Ok(quote! {
+        ::faker_rand::faker_impl_from_file!(#name, #file);
+    })
+}
+
+/// Checks that the input is a non-generic newtype, which is all that the
+/// declarative macros support.
+fn check_newtype(input: &DeriveInput, derive: &str) -> syn::Result<()> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            format!("{} cannot be derived for generic types", derive),
+        ));
+    }
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(()),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                format!(
+                    "{} can only be derived for a newtype, such as `struct {}(String);`",
+                    derive, input.ident
+                ),
+            )),
+        },
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            format!("{} can only be derived for structs", derive),
+        )),
+    }
+}
+
+fn attrs_named<'a>(attrs: &'a [Attribute], name: &'a str) -> impl Iterator<Item = &'a Attribute> {
+    attrs.iter().filter(move |attr| attr.path().is_ident(name))
+}
+
+/// A template pattern, as written in a `#[template(...)]` attribute.
+struct Template {
+    bindings: Vec<(Ident, Type)>,
+    weight: Option<LitInt>,
+    fmt: LitStr,
+    args: Vec<Arg>,
+}
+
+/// An argument to a template pattern.
+enum Arg {
+    Gen(Type),
+    Repeat {
+        min: LitInt,
+        max: LitInt,
+        sep: Expr,
+        arg: Type,
+    },
+}
+
+impl Template {
+    fn is_never_chosen(&self) -> bool {
+        match &self.weight {
+            Some(weight) => weight.base10_parse::<u32>().ok() == Some(0),
+            None => false,
+        }
+    }
+}
+
+impl Parse for Template {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut bindings = Vec::new();
+        while input.peek(Token![let]) {
+            input.parse::<Token![let]>()?;
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let ty = input.parse()?;
+            input.parse::<Token![;]>()?;
+            bindings.push((name, ty));
+        }
+
+        let weight = if input.peek(LitInt) {
+            let weight: LitInt = input.parse()?;
+            weight.base10_parse::<u32>()?;
+            input.parse::<Token![=>]>()?;
+            Some(weight)
+        } else {
+            None
+        };
+
+        if !input.peek(LitStr) {
+            return Err(input.error("expected a format string literal here"));
+        }
+
+        let fmt: LitStr = input.parse()?;
+
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            args.push(input.parse()?);
+        }
+
+        Ok(Template {
+            bindings,
+            weight,
+            fmt,
+            args,
+        })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let is_repeat = input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "repeat")
+            && input.peek2(syn::token::Paren);
+
+        if !is_repeat {
+            return Ok(Arg::Gen(input.parse()?));
+        }
+
+        input.parse::<Ident>()?;
+        let content;
+        syn::parenthesized!(content in input);
+
+        let min: LitInt = content.parse()?;
+        content.parse::<Token![..=]>()?;
+        let max: LitInt = content.parse()?;
+        if min.base10_parse::<usize>()? > max.base10_parse::<usize>()? {
+            return Err(Error::new(
+                join_spans(&min, &max),
+                "repeat counts must be a non-empty range",
+            ));
+        }
+
+        content.parse::<Token![,]>()?;
+        let sep = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let arg = content.parse()?;
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Arg::Repeat { min, max, sep, arg })
+    }
+}
+
+fn join_spans(first: &LitInt, last: &LitInt) -> Span {
+    first
+        .span()
+        .join(last.span())
+        .unwrap_or_else(|| first.span())
+}
+
+impl quote::ToTokens for Template {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fmt = &self.fmt;
+        let args = &self.args;
+
+        for (name, ty) in &self.bindings {
+            tokens.extend(quote! { let #name = #ty; });
+        }
+
+        if let Some(weight) = &self.weight {
+            tokens.extend(quote! { #weight => });
+        }
+
+        if args.is_empty() {
+            tokens.extend(quote! { #fmt; });
+        } else {
+            tokens.extend(quote! { #fmt, #(#args),*; });
+        }
+    }
+}
+
+impl quote::ToTokens for Arg {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            Arg::Gen(arg) => quote! { #arg },
+            Arg::Repeat { min, max, sep, arg } => quote! { repeat(#min..=#max, #sep, #arg) },
+        });
+    }
+}
@@ -41,17 +41,17 @@
 //!
 //! // This output is deterministic. Unlike the previous examples (which used a
 //! // global, randomly-seeded RNG), we will get the same result here each time.
-//! assert_eq!("Melvin", rng.gen::<FirstName>().to_string());
-//! assert_eq!("Jamey", rng.gen::<FirstName>().to_string());
-//! assert_eq!("Price", rng.gen::<LastName>().to_string());
+//! assert_eq!("Michael", rng.gen::<FirstName>().to_string());
+//! assert_eq!("Joseph", rng.gen::<FirstName>().to_string());
+//! assert_eq!("MacGyver", rng.gen::<LastName>().to_string());
 //!
 //! // As a demonstration of the deterministic behavior, let's reset rng back to
 //! // its initial state. We'll get back the same generated data the second time
 //! // around.
 //! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-//! assert_eq!("Melvin", rng.gen::<FirstName>().to_string());
-//! assert_eq!("Jamey", rng.gen::<FirstName>().to_string());
-//! assert_eq!("Price", rng.gen::<LastName>().to_string());
+//! assert_eq!("Michael", rng.gen::<FirstName>().to_string());
+//! assert_eq!("Joseph", rng.gen::<FirstName>().to_string());
+//! assert_eq!("MacGyver", rng.gen::<LastName>().to_string());
 //! ```
 //!
 //! # How it works
@@ -66,12 +66,70 @@
 //! [`String`].
 //!
 //! Under the hood, almost all of the types exposed by this crate are just
-//! "newtype" wrappers around [`String`] (e.g. `struct Foo(String)`). It's
+//! "newtype" wrappers around [`String`] (e.g. `struct Foo(String)`). Generators
+//! that choose from a fixed list of words, such as
+//! [`en_us::names::FirstName`], instead wrap a `&'static str` borrowed from
+//! that list, so that generating them doesn't allocate. It's
 //! expected that most users of this crate will never pass around instances of
 //! generators; instead, it's more common to pass a generator type as a type
 //! parameter to [`rand::Rng::gen`], and then immediately convert the result to
 //! a [`String`].
 //!
+//! Converting with `to_string` goes through [`std::fmt::Display`], and so makes
+//! a copy of the generated data. To move the [`String`] out of a generator
+//! instead, you can use the generator's `into_inner` method, or its `From<T>
+//! for String` implementation. Generators also implement [`AsRef<str>`] and
+//! [`Deref<Target = str>`][`std::ops::Deref`], so you can borrow the generated
+//! data as a `&str` without copying it:
+//!
+//! ```
+//! use rand::{Rng, SeedableRng};
+//! use faker_rand::en_us::names::FirstName;
+//!
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//!
+//! fn greet(name: &str) -> String {
+//!     format!("Hello, {}!", name)
+//! }
+//!
+//! let name = rng.gen::<FirstName>();
+//! assert_eq!("Michael", name.as_ref());
+//! assert_eq!("Michael", &*name);
+//! assert_eq!("Hello, Michael!", greet(&name));
+//! assert_eq!(7, name.len());
+//! assert_eq!("Michael", name.into_inner());
+//!
+//! let name: String = rng.gen::<FirstName>().into();
+//! assert_eq!("Joseph", name);
+//!
+//! // For generators that wrap a String, into_inner and into move the String
+//! // out without reallocating it.
+//! use faker_rand::en_us::names::FullName;
+//! let name = rng.gen::<FullName>();
+//! let ptr = name.as_ptr();
+//! let name: String = name.into();
+//! assert_eq!(ptr, name.as_ptr());
+//! ```
+//!
+//! Generators also implement [`Debug`], [`Clone`], [`PartialEq`], [`Eq`], and
+//! [`Hash`][`std::hash::Hash`] by forwarding to their [`String`], so you can
+//! store generated values in structs or collections directly:
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use rand::{Rng, SeedableRng};
+//! use faker_rand::en_us::internet::Email;
+//!
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//!
+//! let email = rng.gen::<Email>();
+//! assert_eq!("\"odietrich48@thompson.net\"", format!("{:?}", email));
+//!
+//! let mut seen = HashSet::new();
+//! assert!(seen.insert(email.clone()));
+//! assert!(!seen.insert(email));
+//! ```
+//!
 //! # Creating your own generators
 //!
 //! In addition to the base set of generators in this crate, `faker_rand`
@@ -92,9 +150,84 @@
 //!    generate a first and a last name, and then put a space between them,
 //!    something like: `format!("{} {}", gen_first_name(), gen_last_name())`.
 //!
-//! This crate provides [`faker_impl_from_file`] to support the first pattern,
-//! and [`faker_impl_from_templates`] to support the second pattern. See the
-//! documentation for those macros for specifics on how to use them.
+//! This crate provides [`faker_impl_from_file`] and [`faker_impl_from_words`]
+//! to support the first pattern, and [`faker_impl_from_templates`] to support
+//! the second pattern. When several columns of data must stay consistent with
+//! each other, such as a city and its state, [`faker_impl_from_csv`] chooses a
+//! whole row of a file at a time. If some template patterns should be chosen
+//! more often than others, both [`faker_impl_from_templates`] and
+//! [`faker_impl_from_weighted_templates`] let you give each pattern a weight.
+//! Similarly, [`faker_impl_from_weighted_generators`] chooses between whole
+//! generators according to their weights. For identifiers made up of random
+//! digits and letters, such as `"###-??-####"`, [`faker_impl_from_pattern`] is
+//! usually more concise than templates. See the documentation for those macros
+//! for specifics on how to use them.
+//!
+//! For short lists of words, [`faker_impl_from_words`] saves you from creating
+//! a separate file:
+//!
+//! ```
+//! use faker_rand::faker_impl_from_words;
+//!
+//! struct Size(&'static str);
+//! faker_impl_from_words!(Size, ["small", "medium", "large"]);
+//!
+//! use rand::{Rng, SeedableRng};
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//!
+//! assert_eq!("large", rng.gen::<Size>().to_string());
+//! assert_eq!("medium", rng.gen::<Size>().to_string());
+//! ```
+//!
+//! If the list of words isn't known until your program runs, for instance
+//! because your users supply it, load it into a [`runtime::WordList`] instead.
+//! [`runtime::FromWordList`] lets such a list be used inside templates.
+//!
+//! The [`util`] module provides small generators that are useful as building
+//! blocks in templates, such as single characters ([`util::AsciiDigit`],
+//! [`util::AsciiLowercase`], [`util::AsciiUppercase`]) and integers
+//! ([`util::NumberInRange`]):
+//!
+//! ```
+//! use faker_rand::faker_impl_from_templates;
+//! use faker_rand::util::{AsciiUppercase, NumberInRange};
+//!
+//! // A seat assignment on a plane, like "23F".
+//! struct Seat(String);
+//! faker_impl_from_templates! {
+//!     Seat;
+//!
+//!     "{}{}", NumberInRange<1, 40>, AsciiUppercase;
+//! }
+//!
+//! use rand::{Rng, SeedableRng};
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//!
+//! assert_eq!("3O", rng.gen::<Seat>().to_string());
+//! ```
+//!
+//! If you enable this crate's `derive` feature, you can use
+//! `#[derive(FakeTemplate)]` and `#[derive(FakeWordFile)]` instead of
+//! [`faker_impl_from_templates`] and [`faker_impl_from_file`]. They generate the
+//! same implementations, but point at the offending token when a template is
+//! malformed, and don't require any trailing semicolons:
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use faker_rand::FakeTemplate;
+//! use faker_rand::util::{AsciiUppercase, NumberInRange};
+//!
+//! #[derive(FakeTemplate)]
+//! #[template("{}{}", NumberInRange<1, 40>, AsciiUppercase)]
+//! struct Seat(String);
+//!
+//! use rand::{Rng, SeedableRng};
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//!
+//! assert_eq!("3O", rng.gen::<Seat>().to_string());
+//! # }
+//! ```
 //!
 //! ## Advanced generators
 //!
@@ -157,30 +290,296 @@
 //! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
 //!
 //! // you can use Demo as your own custom generator now!
-//! assert_eq!("cletastad shanie russel dds", rng.gen::<Demo>().to_string());
-//! assert_eq!("robinmouth pablo bayer", rng.gen::<Demo>().to_string());
-//! assert_eq!("west gregory trevor cronin", rng.gen::<Demo>().to_string());
+//! assert_eq!("sarahmouth dr. skye kunze dvm", rng.gen::<Demo>().to_string());
+//! assert_eq!("lindafurt king mcdermott phd", rng.gen::<Demo>().to_string());
+//! assert_eq!("susanshire marvin pacocha ii", rng.gen::<Demo>().to_string());
 //! ```
 //!
 //! This pattern is used within this crate to make utility generators like
 //! [`util::ToAsciiLowercase`] or [`util::CapitalizeFirstLetter`]. If you've
 //! created a generator that you feel could be useful to others, please consider
 //! opening a pull request to add it to this crate!
+//!
+//! # Other kinds of filler text
+//!
+//! Besides lorem ipsum, this crate can generate themed filler text. Each theme
+//! is behind its own feature, so that you only pay for the word lists you use:
+//!
+//! * The `hipster` feature enables `lorem::hipster`, for text like "Artisan
+//!   kombucha fixie tote."
+//! * The `corporate` feature enables `lorem::corporate`, for text like
+//!   "Leverage synergy going forward."
+//!
+//! These modules provide a `Word`, `Sentence`, and `Paragraph` generator, which
+//! work like their [`lorem`] counterparts.
+//!
+//! # Serde support
+//!
+//! If you enable this crate's `serde` feature, every generator will implement
+//! `serde::Serialize`, including generators you create using this crate's
+//! macros. Generators serialize as their underlying string, so they can be used
+//! directly as fields in your own serializable types:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use rand::{Rng, SeedableRng};
+//! use serde::Serialize;
+//! use faker_rand::en_us::names::{FirstName, FullName};
+//! use faker_rand::en_us::internet::Email;
+//! use faker_rand::util::ToAsciiLowercase;
+//!
+//! #[derive(Serialize)]
+//! struct User {
+//!     name: FullName,
+//!     email: Email,
+//!     nickname: ToAsciiLowercase<FirstName>,
+//! }
+//!
+//! let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+//! let user = User { name: rng.gen(), email: rng.gen(), nickname: rng.gen() };
+//!
+//! assert_eq!(
+//!     r#"{"name":"Sarah Simonis DVM","email":"skyekunze@runolfsdottir.biz","nickname":"jadon"}"#,
+//!     serde_json::to_string(&user).unwrap()
+//! );
+//!
+//! // Generators can also be serialized on their own.
+//! let name: FirstName = rng.gen();
+//! assert_eq!(r#""Lexie""#, serde_json::to_string(&name).unwrap());
+//!
+//! let name: FullName = rng.gen();
+//! assert_eq!(r#""Shanie Leannon DDS""#, serde_json::to_string(&name).unwrap());
+//! # }
+//! ```
+
+/// Implements the traits and methods shared by all generators.
+///
+/// This is an implementation detail of the other macros in this crate. The
+/// bracketed tokens are the generic parameters of the type, which must be a
+/// newtype whose first member is a [`String`] or a `&'static str`. Any
+/// expressions after the type are used to construct the type's remaining
+/// members when cloning it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faker_impl_newtype {
+    ([$($params: tt)*] $name: ty $(, $rest: expr)*) => {
+        impl<$($params)*> $name {
+            /// Consumes the generated value, returning the underlying [`String`].
+            #[allow(dead_code)]
+            pub fn into_inner(self) -> String {
+                $crate::__into_string(self.0)
+            }
+        }
+
+        impl<$($params)*> std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl<$($params)*> AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl<$($params)*> std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl<$($params)*> From<$name> for String {
+            fn from(value: $name) -> String {
+                $crate::__into_string(value.0)
+            }
+        }
+
+        impl<$($params)*> std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl<$($params)*> Clone for $name {
+            fn clone(&self) -> Self {
+                Self(self.0.clone() $(, $rest)*)
+            }
+        }
+
+        impl<$($params)*> PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$($params)*> Eq for $name {}
+
+        impl<$($params)*> std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        $crate::__faker_impl_serialize!([$($params)*] $name);
+    };
+}
+
+/// Converts the first member of a generator into a [`String`].
+///
+/// This is an implementation detail of [`__faker_impl_newtype`]. It moves
+/// [`String`]s without reallocating them, and copies `&'static str`s.
+#[doc(hidden)]
+pub fn __into_string<S: Into<String>>(s: S) -> String {
+    s.into()
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
+#[cfg(feature = "derive")]
+pub use faker_rand_derive::{FakeTemplate, FakeWordFile};
+
+/// Strips the line ending from a line of a data file, returning `None` if the
+/// line is blank or a `#` comment and should be skipped.
+///
+/// Every way of loading lines of data, at compile time or at runtime, goes
+/// through this function, so they all agree on which lines count.
+fn data_line(line: &str) -> Option<&str> {
+    let line = line.trim_end_matches('\r');
+    if line.trim().is_empty() || line.starts_with('#') {
+        None
+    } else {
+        Some(line)
+    }
+}
+
+/// The lines of a file loaded by [`faker_impl_from_file`], along with their
+/// weights.
+///
+/// This is an implementation detail of [`faker_impl_from_file`].
+#[doc(hidden)]
+pub struct __WeightedValues {
+    values: Vec<&'static str>,
+
+    // The running total of the weights of values, or empty if every value has
+    // weight 1. In that case, values are chosen by index directly, exactly as
+    // they were before weights were supported.
+    cumulative_weights: Vec<u32>,
+}
+
+impl __WeightedValues {
+    /// Parses the contents of a file, one value per line, skipping blank lines
+    /// and comments. `file` is used only in panic messages.
+    pub fn parse(file: &str, contents: &'static str) -> Self {
+        let mut values = Vec::new();
+        let mut cumulative_weights = Vec::new();
+        let mut total_weight: u32 = 0;
+
+        for line in contents.lines().filter_map(data_line) {
+            let (value, weight) = match line.rsplit_once('\t') {
+                Some((value, weight)) => match weight.parse() {
+                    Ok(weight) if weight > 0 => (value, weight),
+                    _ => panic!(
+                        "{}: invalid weight {:?} for {:?}, weights must be positive integers",
+                        file, weight, value
+                    ),
+                },
+                None => (line, 1),
+            };
+
+            total_weight = total_weight
+                .checked_add(weight)
+                .unwrap_or_else(|| panic!("{}: total weight is too large", file));
+
+            values.push(value);
+            cumulative_weights.push(total_weight);
+        }
+
+        if values.is_empty() {
+            panic!("{}: file contains no values", file);
+        }
+
+        if total_weight as usize == values.len() {
+            cumulative_weights.clear();
+        }
+
+        __WeightedValues {
+            values,
+            cumulative_weights,
+        }
+    }
+
+    /// Chooses a value according to its weight.
+    pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> &'static str {
+        match self.cumulative_weights.last() {
+            None => self.values[rng.gen_range(0..self.values.len())],
+            Some(&total_weight) => {
+                let n = rng.gen_range(0..total_weight);
+                self.values[self.cumulative_weights.partition_point(|&w| w <= n)]
+            }
+        }
+    }
+}
+
+/// Implements `serde::Serialize` for a generator, serializing it as a string.
+///
+/// This macro checks whether the `serde` feature is enabled for `faker_rand`,
+/// rather than for the crate invoking it.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faker_impl_serialize {
+    ([$($params: tt)*] $name: ty) => {
+        impl<$($params)*> $crate::__serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                serializer.serialize_str(&self.0)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faker_impl_serialize {
+    ([$($params: tt)*] $name: ty) => {};
+}
 
 /// Create a generator implementation from a file containing a list of words.
 ///
 /// The first argument to the macro must be the name of type to create an
 /// implementation for. Said type must be a newtype whose first member must be a
-/// [`String`]. The second argument must be a string literal, a path to the file
-/// containing the list of words.
+/// [`String`] or a `&'static str`. The second argument must be a string
+/// literal, a path to the file containing the list of words.
 ///
 /// The macro will generate a
 /// [`Distribution`][`rand::distributions::Distribution`] and
-/// [`Display`][`std::fmt::Display`] implementation for the type.
+/// [`Display`][`std::fmt::Display`] implementation for the type. It will also
+/// generate an `into_inner` method, as well as [`AsRef<str>`],
+/// [`Deref<Target = str>`][`std::ops::Deref`], and `From<T> for String`
+/// implementations, so that the generated [`String`] can be borrowed or moved
+/// out without copying it.
+///
+/// Finally, the macro will implement [`Debug`], [`Clone`], [`PartialEq`],
+/// [`Eq`], and [`Hash`][`std::hash::Hash`] for the type, all of which forward
+/// to the underlying [`String`]. Your newtype should therefore not derive any
+/// of these traits itself.
 ///
 /// Each line of the given file, whose contents will be loaded using
 /// [`std::include_str`], will be used as a possible value to return when the
-/// generator is sampled.
+/// generator is sampled. If the newtype's member is a `&'static str`, sampling
+/// the generator borrows one of these lines rather than allocating a new
+/// [`String`], which makes it faster. Its `into_inner` method, and its
+/// `From<T> for String` implementation, will then copy the line into a new
+/// [`String`].
 ///
 /// ```
 /// use faker_rand::faker_impl_from_file;
@@ -197,990 +596,7680 @@
 ///
 /// assert_eq!("impedit", rng.gen::<Demo>().to_string());
 /// ```
+///
+/// To avoid allocating a [`String`] every time the generator is sampled, use a
+/// `&'static str` newtype instead:
+///
+/// ```
+/// use faker_rand::faker_impl_from_file;
+///
+/// struct Demo(&'static str);
+/// faker_impl_from_file!(Demo, "data/lorem_words");
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("impedit", rng.gen::<Demo>().to_string());
+/// assert_eq!("totam", rng.gen::<Demo>().into_inner());
+/// ```
+///
+/// A line can also end in a tab followed by a positive integer weight, such as
+/// `Mary\t40`. A line with weight 40 is chosen 40 times as often as a line with
+/// no weight, which is the same as weight 1. The weight is not part of the
+/// generated value. For example, [`en_us::names::FirstName`] gives common names
+/// a higher weight. An invalid weight causes a panic the first time the
+/// generator is sampled.
+///
+/// Blank lines, and lines starting with `#`, are skipped, so data files can
+/// carry comments such as attributions. Lines may end in either `\n` or
+/// `\r\n`. If a file contains no values at all, the generator panics the
+/// first time it is sampled.
+///
+/// ```
+/// use faker_rand::faker_impl_from_file;
+///
+/// // data/fixtures/commented_words has a comment, a blank line, and CRLF line
+/// // endings, around the values "alpha", "beta", and "gamma".
+/// struct Demo(&'static str);
+/// faker_impl_from_file!(Demo, "data/fixtures/commented_words");
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("gamma", rng.gen::<Demo>().to_string());
+/// assert_eq!("beta", rng.gen::<Demo>().to_string());
+/// # let mut seen = std::collections::BTreeSet::new();
+/// # for _ in 0..1000 {
+/// #     seen.insert(rng.gen::<Demo>().to_string());
+/// # }
+/// # assert_eq!(vec!["alpha", "beta", "gamma"], seen.into_iter().collect::<Vec<_>>());
+/// ```
+///
+/// ```should_panic
+/// use faker_rand::faker_impl_from_file;
+///
+/// // data/fixtures/only_comments contains nothing but comments.
+/// struct Demo(&'static str);
+/// faker_impl_from_file!(Demo, "data/fixtures/only_comments");
+///
+/// use rand::Rng;
+/// rand::thread_rng().gen::<Demo>();
+/// ```
 #[macro_export]
 macro_rules! faker_impl_from_file {
     ($name: ident, $file: expr) => {
         impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
             fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
-                use lazy_static::lazy_static;
+                static VALUES: std::sync::OnceLock<$crate::__WeightedValues> =
+                    std::sync::OnceLock::new();
 
-                lazy_static! {
-                    static ref VALUES: Vec<String> =
-                        include_str!($file).lines().map(String::from).collect();
-                }
+                let values = VALUES
+                    .get_or_init(|| $crate::__WeightedValues::parse($file, include_str!($file)));
 
-                $name(VALUES[rng.gen_range(0..VALUES.len())].clone())
+                $name(From::from(values.sample(rng)))
             }
         }
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{}", self.0)
+        $crate::__faker_impl_newtype!([] $name);
+    };
+}
+
+/// Create a generator implementation from a list of words.
+///
+/// This macro works like [`faker_impl_from_file`], except that the second
+/// argument must be a non-empty, bracketed, comma-separated list of string
+/// literals, rather than a path to a file. Each of the string literals will be
+/// used as a possible value to return when the generator is sampled. An empty
+/// list is a compile-time error.
+///
+/// ```
+/// use faker_rand::faker_impl_from_words;
+///
+/// // First, declare your newtype wrapper around String or &'static str.
+/// struct Demo(&'static str);
+///
+/// // Then, use the macro.
+/// faker_impl_from_words!(Demo, ["red", "green", "blue"]);
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("blue", rng.gen::<Demo>().to_string());
+/// assert_eq!("green", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// ```compile_fail
+/// use faker_rand::faker_impl_from_words;
+///
+/// struct Demo(&'static str);
+/// faker_impl_from_words!(Demo, []);
+/// ```
+#[macro_export]
+macro_rules! faker_impl_from_words {
+    ($name: ident, [$($word: literal),+ $(,)?]) => {
+        $crate::__faker_impl_from_values!($name, &[$($word),+]);
+    };
+}
+
+/// Implements a generator that chooses from a list of values.
+///
+/// This is an implementation detail of [`faker_impl_from_words`]. The second
+/// argument must be an expression that can be coerced to a `&[&'static str]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __faker_impl_from_values {
+    ($name: ident, $values: expr) => {
+        impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                let values: &[&'static str] = $values;
+                $name(From::from(values[rng.gen_range(0..values.len())]))
             }
         }
+
+        $crate::__faker_impl_newtype!([] $name);
     };
 }
 
-/// Create a generator implementation from a set of format strings and
-/// sub-generators.
+/// Create a generator implementation from a file of comma-separated rows.
 ///
 /// The first argument to the macro must be the name of type to create an
 /// implementation for. Said type must be a newtype whose first member must be a
-/// [`String`]. The first argument must be followed by a semicolon.
+/// [`String`]. The second argument must be a string literal, a path to the file
+/// containing the rows, which will be loaded using [`std::include_str`]. The
+/// third argument must be a string literal, a template for formatting a row.
 ///
-/// After the first argument, the macro accepts a semicolon-separated sequence
-/// of template patterns. A template pattern is a comma-separated sequence
-/// starting with a [`std::format`]-compatible string literal, followed by a
-/// sequence of generator type names. The `{}`s in the string literal will be
-/// replaced by sampled data from each of the given generators.
+/// Each sample chooses one row of the file, and then replaces each `{N}` in the
+/// template with the row's `N`th column, counting from zero. As with
+/// [`std::format`], `{{` and `}}` produce literal braces. Because all of the
+/// columns come from the same row, this is useful for data that must be
+/// consistent across columns, such as a city and the state it's in.
 ///
-/// The macro will generate a
-/// [`Distribution`][`rand::distributions::Distribution`] and
-/// [`Display`][`std::fmt::Display`] implementation for the type.
+/// Columns are separated by commas; there is no way to quote or escape a comma
+/// within a column. Blank lines, and lines starting with `#`, are skipped. The
+/// generator panics the first time it is sampled if the file contains no rows,
+/// if its rows don't all have the same number of columns, or if the template
+/// refers to a column that doesn't exist.
 ///
-/// If multiple template patterns are given, the created implementation will, on
-/// each invocation, choose from one of them with equal likelihood. To bias
-/// generation in favor of one template pattern over another, you can provide
-/// the same template pattern multiple times.
+/// Otherwise, this macro generates the same implementations as
+/// [`faker_impl_from_file`].
 ///
 /// ```
-/// use faker_rand::faker_impl_from_templates;
+/// use faker_rand::faker_impl_from_csv;
 ///
-/// // First, declare your newtype wrapper around String.
+/// // data/fixtures/cities.csv contains rows like "Boston,MA,02108".
 /// struct Demo(String);
-///
-/// // Then, invoke the macro.
-/// //
-/// // Note well: all commas and semicolons in this example, even trailing
-/// // semicolons, are strictly required.
-/// faker_impl_from_templates! {
-///     // The type we're creating a generator implementation for.
-///     Demo;
-///
-///     // The template patterns.
-///     "{}.{}", faker_rand::util::AsciiDigit, faker_rand::lorem::Word;
-///     "{} ~~~ {}", faker_rand::lorem::Word, faker_rand::util::AsciiDigit;
-/// }
+/// faker_impl_from_csv!(Demo, "data/fixtures/cities.csv", "{0}, {1} {2}");
 ///
 /// use rand::{Rng, SeedableRng};
 /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
 ///
-/// assert_eq!("qui ~~~ 5", rng.gen::<Demo>().to_string());
-/// assert_eq!("debitis ~~~ 5", rng.gen::<Demo>().to_string());
-/// assert_eq!("5.aliquid", rng.gen::<Demo>().to_string());
-/// assert_eq!("0.doloribus", rng.gen::<Demo>().to_string());
+/// assert_eq!("Seattle, WA 98101", rng.gen::<Demo>().to_string());
+/// assert_eq!("Denver, CO 80202", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// A row with the wrong number of columns causes a panic:
+///
+/// ```should_panic
+/// use faker_rand::faker_impl_from_csv;
+///
+/// // The second row of data/fixtures/ragged.csv is missing a column.
+/// struct Demo(String);
+/// faker_impl_from_csv!(Demo, "data/fixtures/ragged.csv", "{0}, {1} {2}");
+///
+/// use rand::Rng;
+/// rand::thread_rng().gen::<Demo>();
 /// ```
 #[macro_export]
-macro_rules! faker_impl_from_templates {
-    ($name: ident; $($fmt: expr, $($arg:ty),+);+;) => {
+macro_rules! faker_impl_from_csv {
+    ($name: ident, $file: expr, $template: expr) => {
         impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
             fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
-                use rand::seq::SliceRandom;
-                let funcs: Vec<Box<dyn Fn(&mut R) -> String>> = vec![
-                    $(
-                        Box::new(|rng| {
-                            format!($fmt, $(
-                                rng.gen::<$arg>().to_string(),
-                            )*)
-                        }),
-                    )*
-                ];
+                static ROWS: std::sync::OnceLock<$crate::__CsvRows> = std::sync::OnceLock::new();
 
-                $name(funcs.choose(rng).unwrap()(rng))
-            }
-        }
+                let rows = ROWS
+                    .get_or_init(|| $crate::__CsvRows::parse($file, include_str!($file), $template));
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{}", self.0)
+                $name(rows.sample(rng))
             }
         }
-    }
+
+        $crate::__faker_impl_newtype!([] $name);
+    };
+}
+
+/// The rows of a file loaded by [`faker_impl_from_csv`], along with the
+/// template for formatting them.
+///
+/// This is an implementation detail of [`faker_impl_from_csv`].
+#[doc(hidden)]
+pub struct __CsvRows {
+    rows: Vec<Vec<&'static str>>,
+    template: Vec<CsvTemplatePart>,
+}
+
+enum CsvTemplatePart {
+    Literal(String),
+    Column(usize),
+}
+
+impl __CsvRows {
+    /// Parses the contents of a file, one row per line, skipping blank lines
+    /// and comments. `file` is used only in panic messages.
+    pub fn parse(file: &str, contents: &'static str, template: &str) -> Self {
+        let mut rows: Vec<Vec<&'static str>> = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = match data_line(line) {
+                Some(line) => line,
+                None => continue,
+            };
+
+            let row: Vec<_> = line.split(',').collect();
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    panic!(
+                        "{}:{}: expected {} columns, found {}",
+                        file,
+                        i + 1,
+                        first.len(),
+                        row.len()
+                    );
+                }
+            }
+
+            rows.push(row);
+        }
+
+        let columns = match rows.first() {
+            Some(row) => row.len(),
+            None => panic!("{}: file contains no rows", file),
+        };
+
+        let template = Self::parse_template(template);
+        for part in &template {
+            if let CsvTemplatePart::Column(n) = part {
+                if *n >= columns {
+                    panic!(
+                        "{}: template refers to column {}, but rows have only {} columns",
+                        file, n, columns
+                    );
+                }
+            }
+        }
+
+        __CsvRows { rows, template }
+    }
+
+    fn parse_template(template: &str) -> Vec<CsvTemplatePart> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let column = rest
+                        .find('}')
+                        .and_then(|end| rest[..end].parse().ok().map(|n| (n, end)));
+
+                    match column {
+                        Some((n, end)) => {
+                            chars = rest[end + 1..].chars();
+                            parts.push(CsvTemplatePart::Literal(std::mem::take(&mut literal)));
+                            parts.push(CsvTemplatePart::Column(n));
+                        }
+                        None => panic!(
+                            "invalid template {:?}: expected a column number after '{{'",
+                            template
+                        ),
+                    }
+                }
+                '}' => panic!("invalid template {:?}: unmatched '}}'", template),
+                _ => literal.push(c),
+            }
+        }
+
+        parts.push(CsvTemplatePart::Literal(literal));
+        parts
+    }
+
+    /// Chooses a row, and formats it using the template.
+    pub fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let row = &self.rows[rng.gen_range(0..self.rows.len())];
+
+        let mut s = String::new();
+        for part in &self.template {
+            match part {
+                CsvTemplatePart::Literal(literal) => s.push_str(literal),
+                CsvTemplatePart::Column(n) => s.push_str(row[*n]),
+            }
+        }
+
+        s
+    }
+}
+
+/// Create a generator implementation from a set of format strings and
+/// sub-generators.
+///
+/// The first argument to the macro must be the name of type to create an
+/// implementation for. Said type must be a newtype whose first member must be a
+/// [`String`]. The first argument must be followed by a semicolon.
+///
+/// After the first argument, the macro accepts a semicolon-separated sequence
+/// of template patterns. A template pattern is a comma-separated sequence
+/// starting with a [`std::format`]-compatible string literal, followed by a
+/// sequence of generator type names. The `{}`s in the string literal will be
+/// replaced by sampled data from each of the given generators.
+///
+/// The macro will generate a
+/// [`Distribution`][`rand::distributions::Distribution`] and
+/// [`Display`][`std::fmt::Display`] implementation for the type. It will also
+/// generate an `into_inner` method, as well as [`AsRef<str>`],
+/// [`Deref<Target = str>`][`std::ops::Deref`], and `From<T> for String`
+/// implementations, so that the generated [`String`] can be borrowed or moved
+/// out without copying it.
+///
+/// Finally, the macro will implement [`Debug`], [`Clone`], [`PartialEq`],
+/// [`Eq`], and [`Hash`][`std::hash::Hash`] for the type, all of which forward
+/// to the underlying [`String`]. Your newtype should therefore not derive any
+/// of these traits itself.
+///
+/// If multiple template patterns are given, the created implementation will, on
+/// each invocation, choose from one of them with equal likelihood. To bias
+/// generation in favor of one template pattern over another, you can precede
+/// a template pattern with an integer weight and `=>`. Template patterns
+/// without a weight have a weight of 1, and a template pattern with a weight of
+/// 0 is never chosen. At least one weight must be non-zero, which is checked at
+/// compile time.
+///
+/// The template pattern is chosen with a single call to
+/// [`rand::Rng::gen_range`] over the sum of the weights, so adding a weight of
+/// 1 to every template pattern doesn't change the output for a given RNG.
+///
+/// ```
+/// use faker_rand::faker_impl_from_templates;
+///
+/// // First, declare your newtype wrapper around String.
+/// struct Demo(String);
+///
+/// // Then, invoke the macro.
+/// //
+/// // Note well: all commas and semicolons in this example, even trailing
+/// // semicolons, are strictly required.
+/// faker_impl_from_templates! {
+///     // The type we're creating a generator implementation for.
+///     Demo;
+///
+///     // The template patterns.
+///     "{}.{}", faker_rand::util::AsciiDigit, faker_rand::lorem::Word;
+///     "{} ~~~ {}", faker_rand::lorem::Word, faker_rand::util::AsciiDigit;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("qui ~~~ 5", rng.gen::<Demo>().to_string());
+/// assert_eq!("debitis ~~~ 5", rng.gen::<Demo>().to_string());
+/// assert_eq!("5.aliquid", rng.gen::<Demo>().to_string());
+/// assert_eq!("0.doloribus", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// Here's an example with weights:
+///
+/// ```
+/// use faker_rand::faker_impl_from_templates;
+/// use faker_rand::lorem::Word;
+/// use faker_rand::util::AsciiDigit;
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     // Chosen three times as often as the unweighted pattern below.
+///     3 => "{}.{}", AsciiDigit, Word;
+///     "{} ~~~ {}", Word, AsciiDigit;
+///
+///     // Never chosen.
+///     0 => "{} !!! {}", Word, Word;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("5.adipisci", rng.gen::<Demo>().to_string());
+/// assert_eq!("4.dolorem", rng.gen::<Demo>().to_string());
+/// assert_eq!("sequi ~~~ 5", rng.gen::<Demo>().to_string());
+/// assert_eq!("0.in", rng.gen::<Demo>().to_string());
+/// # let mut counts = [0; 3];
+/// # for _ in 0..100000 {
+/// #     let s = rng.gen::<Demo>().to_string();
+/// #     if s.contains(" ~~~ ") {
+/// #         counts[1] += 1;
+/// #     } else if s.contains(" !!! ") {
+/// #         counts[2] += 1;
+/// #     } else {
+/// #         counts[0] += 1;
+/// #     }
+/// # }
+/// # assert!((73000..77000).contains(&counts[0]), "{:?}", counts);
+/// # assert!((23000..27000).contains(&counts[1]), "{:?}", counts);
+/// # assert_eq!(0, counts[2]);
+/// ```
+///
+/// ```compile_fail
+/// use faker_rand::faker_impl_from_templates;
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     0 => "{}", faker_rand::lorem::Word;
+///     0 => "{}", faker_rand::util::AsciiDigit;
+/// }
+/// ```
+///
+/// In place of a generator type name, a template pattern can also contain
+/// `repeat(MIN..=MAX, SEP, T)`, where `MIN` and `MAX` are integer literals,
+/// `SEP` is a `&str`, and `T` is a generator type name. This fills in a single
+/// `{}` with between `MIN` and `MAX` (inclusive) samples from `T`, separated
+/// by `SEP`. The number of samples is chosen using the RNG, before any of the
+/// samples are generated. `MIN` must be no greater than `MAX`.
+///
+/// ```
+/// use faker_rand::faker_impl_from_templates;
+/// use faker_rand::lorem::Word;
+/// use faker_rand::util::AsciiUppercase;
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     "{}: {}", repeat(2..=3, "", AsciiUppercase), repeat(1..=4, ", ", Word);
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("BOV: sequi, aut, assumenda, cupiditate", rng.gen::<Demo>().to_string());
+/// assert_eq!("LPX: voluptas, facere, repudiandae, a", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// ```compile_fail
+/// use faker_rand::faker_impl_from_templates;
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     "{}", repeat(3..=2, " ", faker_rand::lorem::Word);
+/// }
+/// ```
+///
+/// The string literal is passed to [`std::format`] as is, so `{{` and `}}`
+/// produce literal braces, and positional placeholders such as `{0}` can use
+/// the same sample more than once. Each generator is sampled exactly once, in
+/// the order the generators are listed, no matter how many times its sample
+/// appears in the string literal.
+///
+/// ```
+/// use faker_rand::faker_impl_from_templates;
+/// use faker_rand::en_us::internet::{Domain, Username};
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     r#"{{"user": "{0}", "email": "{0}@{1}"}}"#, Username, Domain;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!(
+///     r#"{"user": "odietrich48", "email": "odietrich48@thompson.net"}"#,
+///     rng.gen::<Demo>().to_string()
+/// );
+/// # for _ in 0..1000 {
+/// #     let s = rng.gen::<Demo>().to_string();
+/// #     let user = s.split('"').nth(3).unwrap();
+/// #     assert!(s.starts_with("{\"user\": ") && s.ends_with('}'), "{}", s);
+/// #     assert!(s.contains(&format!("\"email\": \"{}@", user)), "{}", s);
+/// # }
+/// ```
+///
+/// To use the same sample in more than one way, a template pattern can be
+/// preceded by bindings, written `let NAME = T;`. Each binding is sampled once,
+/// before the template pattern's generators, and the sample can be used in the
+/// string literal as `{NAME}`. `NAME` can also be used in place of a generator
+/// type name, in which case it always generates the bound sample, including
+/// when it's wrapped in another generator such as [`util::ToAsciiLowercase`].
+/// Bindings only apply to the template pattern that follows them, and a
+/// template pattern that only uses bindings doesn't need any generators.
+///
+/// ```
+/// use faker_rand::faker_impl_from_templates;
+/// use faker_rand::en_us::internet::Domain;
+/// use faker_rand::en_us::names::{FirstName, LastName};
+/// use faker_rand::util::ToAsciiLowercase;
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     let first = FirstName;
+///     let last = LastName;
+///     "{first} {last} <{}.{}@{}>", ToAsciiLowercase<first>, ToAsciiLowercase<last>, Domain;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("Joseph MacGyver <joseph.macgyver@mcclure.info>", rng.gen::<Demo>().to_string());
+/// assert_eq!("Rachel Thompson <rachel.thompson@runolfsdottir.biz>", rng.gen::<Demo>().to_string());
+/// # for _ in 0..1000 {
+/// #     let s = rng.gen::<Demo>().to_string();
+/// #     let (name, email) = s.split_once(" <").unwrap();
+/// #     let (first, last) = name.split_once(' ').unwrap();
+/// #     let local = email.split('@').next().unwrap();
+/// #     let lower = |s: &str| s.chars().filter(char::is_ascii_alphanumeric).collect::<String>().to_ascii_lowercase();
+/// #     let expected = format!("{}.{}", lower(first), lower(last));
+/// #     assert_eq!(expected, local, "{}", s);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! faker_impl_from_templates {
+    (@munch $name: ident [$($acc: tt)*] []) => {
+        impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $crate::faker_impl_from_templates!(@sample $name rng [$($acc)*])
+            }
+        }
+
+        $crate::__faker_impl_newtype!([] $name);
+    };
+
+    (@munch $name: ident [$($acc: tt)*] [$($binds: tt)*] let $bind: ident = $bind_ty: ty; $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@munch $name [$($acc)*] [$($binds)* ($bind $bind_ty)] $($rest)*);
+    };
+
+    (@munch $name: ident [$($acc: tt)*] [$($binds: tt)*] $weight: expr => $fmt: expr, $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@args $name [$($acc)*] ($weight, $fmt, [$($binds)*]) [] $($rest)*);
+    };
+
+    (@munch $name: ident [$($acc: tt)*] [$($binds: tt)*] $weight: expr => $fmt: expr; $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@munch $name [$($acc)* ($weight, $fmt, [$($binds)*], [])] [] $($rest)*);
+    };
+
+    (@munch $name: ident [$($acc: tt)*] [$($binds: tt)*] $fmt: expr, $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@args $name [$($acc)*] (1, $fmt, [$($binds)*]) [] $($rest)*);
+    };
+
+    (@munch $name: ident [$($acc: tt)*] [$($binds: tt)*] $fmt: expr; $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@munch $name [$($acc)* (1, $fmt, [$($binds)*], [])] [] $($rest)*);
+    };
+
+    (@args $name: ident [$($acc: tt)*] ($weight: expr, $fmt: expr, $binds: tt) [$($args: tt)*] repeat($min: literal..=$max: literal, $sep: expr, $arg: ty); $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@munch $name [$($acc)* ($weight, $fmt, $binds, [$($args)* (repeat $min, $max, $sep, $arg)])] [] $($rest)*);
+    };
+
+    (@args $name: ident [$($acc: tt)*] ($weight: expr, $fmt: expr, $binds: tt) [$($args: tt)*] repeat($min: literal..=$max: literal, $sep: expr, $arg: ty), $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@args $name [$($acc)*] ($weight, $fmt, $binds) [$($args)* (repeat $min, $max, $sep, $arg)] $($rest)*);
+    };
+
+    (@args $name: ident [$($acc: tt)*] ($weight: expr, $fmt: expr, $binds: tt) [$($args: tt)*] $arg: ty; $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@munch $name [$($acc)* ($weight, $fmt, $binds, [$($args)* (gen $arg)])] [] $($rest)*);
+    };
+
+    (@args $name: ident [$($acc: tt)*] ($weight: expr, $fmt: expr, $binds: tt) [$($args: tt)*] $arg: ty, $($rest: tt)*) => {
+        $crate::faker_impl_from_templates!(@args $name [$($acc)*] ($weight, $fmt, $binds) [$($args)* (gen $arg)] $($rest)*);
+    };
+
+    // Samples a binding once, and declares a generator type of the same name
+    // that always returns that sample, so that the binding can be used in
+    // place of a generator type name, including inside other generators.
+    (@bind $rng: ident ($bind: ident $bind_ty: ty)) => {
+        #[allow(non_camel_case_types)]
+        struct $bind {
+            value: String,
+        }
+
+        impl $bind {
+            fn slot() -> &'static std::thread::LocalKey<std::cell::RefCell<String>> {
+                thread_local! {
+                    static VALUE: std::cell::RefCell<String> = std::cell::RefCell::new(String::new());
+                }
+
+                &VALUE
+            }
+        }
+
+        impl rand::distributions::Distribution<$bind> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, _rng: &mut R) -> $bind {
+                $bind {
+                    value: $bind::slot().with(|value| value.borrow().clone()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $bind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+
+        let $bind = $rng.gen::<$bind_ty>().to_string();
+        $bind::slot().with(|value| value.borrow_mut().clone_from(&$bind));
+    };
+
+    (@arg $rng: ident (gen $arg: ty)) => {
+        $rng.gen::<$arg>().to_string()
+    };
+
+    (@arg $rng: ident (repeat $min: literal, $max: literal, $sep: expr, $arg: ty)) => {{
+        const _: () = assert!($min <= $max, "repeat counts must be a non-empty range");
+
+        let n: usize = $rng.gen_range($min..=$max);
+        let mut s = String::new();
+        for i in 0..n {
+            if i > 0 {
+                s.push_str($sep);
+            }
+
+            s.push_str(&$rng.gen::<$arg>().to_string());
+        }
+
+        s
+    }};
+
+    (@sample $name: ident $rng: ident [$(($weight: expr, $fmt: expr, [$($bind: tt)*], [$($arg: tt)*]))+]) => {{
+        const _: () = {
+            let total: u32 = 0 $(+ $weight)+;
+            assert!(total > 0, "template weights must not all be zero");
+        };
+
+        let weights: &[u32] = &[$($weight),+];
+
+        // Patterns without arguments or bindings never use rng.
+        #[allow(unused_variables)]
+        let funcs: &[fn(&mut R) -> String] = &[
+            $(
+                |rng| {
+                    $(
+                        $crate::faker_impl_from_templates!(@bind rng $bind);
+                    )*
+
+                    format!($fmt, $(
+                        $crate::faker_impl_from_templates!(@arg rng $arg),
+                    )*)
+                },
+            )+
+        ];
+
+        let mut n = $rng.gen_range(0..weights.iter().sum::<u32>());
+        let mut i = 0;
+        while n >= weights[i] {
+            n -= weights[i];
+            i += 1;
+        }
+
+        $name(funcs[i]($rng))
+    }};
+
+    ($name: ident; $($rest: tt)+) => {
+        $crate::faker_impl_from_templates!(@munch $name [] [] $($rest)+);
+    };
+}
+
+/// Create a generator implementation from a set of weighted format strings and
+/// sub-generators.
+///
+/// This macro works like [`faker_impl_from_templates`], except that each
+/// template pattern must be preceded by an integer weight and `=>`. On each
+/// invocation, the created implementation will choose a template pattern with
+/// probability proportional to its weight.
+///
+/// This is the same as [`faker_impl_from_templates`] with a weight on every
+/// template pattern, so the two macros generate the same output for the same
+/// RNG and weights.
+///
+/// Weights must be non-negative integers, and at least one of them must be
+/// non-zero, which is checked at compile time.
+///
+/// ```
+/// use faker_rand::faker_impl_from_weighted_templates;
+///
+/// // First, declare your newtype wrapper around String.
+/// struct Demo(String);
+///
+/// // Then, invoke the macro. In this example, the first template pattern will
+/// // be chosen three times as often as the second.
+/// //
+/// // Note well: all commas and semicolons in this example, even trailing
+/// // semicolons, are strictly required.
+/// faker_impl_from_weighted_templates! {
+///     // The type we're creating a generator implementation for.
+///     Demo;
+///
+///     // The weighted template patterns.
+///     3 => "{}.{}", faker_rand::util::AsciiDigit, faker_rand::lorem::Word;
+///     1 => "{} ~~~ {}", faker_rand::lorem::Word, faker_rand::util::AsciiDigit;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("5.adipisci", rng.gen::<Demo>().to_string());
+/// assert_eq!("4.dolorem", rng.gen::<Demo>().to_string());
+/// assert_eq!("sequi ~~~ 5", rng.gen::<Demo>().to_string());
+/// assert_eq!("0.in", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// ```compile_fail
+/// use faker_rand::faker_impl_from_weighted_templates;
+///
+/// struct Demo(String);
+/// faker_impl_from_weighted_templates! {
+///     Demo;
+///
+///     0 => "{}", faker_rand::lorem::Word;
+/// }
+/// ```
+#[macro_export]
+macro_rules! faker_impl_from_weighted_templates {
+    ($name: ident; $($weight: expr => $fmt: expr, $($arg:ty),+);+;) => {
+        $crate::faker_impl_from_templates! {
+            $name;
+
+            $($weight => $fmt, $($arg),+;)+
+        }
+    }
+}
+
+/// Create a generator implementation from a set of weighted sub-generators.
+///
+/// The first argument to the macro must be the name of type to create an
+/// implementation for. Said type must be a newtype whose first member must be a
+/// [`String`]. The first argument must be followed by a semicolon.
+///
+/// After the first argument, the macro accepts a semicolon-separated sequence
+/// of weighted generators. A weighted generator is an integer weight, followed
+/// by `=>`, followed by a generator type name. On each invocation, the created
+/// implementation will choose one of the generators with probability
+/// proportional to its weight, and return its output unchanged.
+///
+/// Weights must be positive integers; a weight of zero is a compile-time error.
+/// The generator is chosen with a single call to [`rand::Rng::gen_range`] over
+/// the sum of the weights, so the output is deterministic for a given RNG.
+///
+/// As with the other macros in this crate, the macro will generate
+/// [`Distribution`][`rand::distributions::Distribution`],
+/// [`Display`][`std::fmt::Display`], [`AsRef<str>`],
+/// [`Deref<Target = str>`][`std::ops::Deref`], `From<T> for String`,
+/// [`Debug`], [`Clone`], [`PartialEq`], [`Eq`], and
+/// [`Hash`][`std::hash::Hash`] implementations for the type, as well as an
+/// `into_inner` method. Your newtype should therefore not derive any of these
+/// traits itself.
+///
+/// ```
+/// use faker_rand::faker_impl_from_weighted_generators;
+///
+/// // First, declare your newtype wrapper around String.
+/// struct Demo(String);
+///
+/// // Then, invoke the macro. In this example, American email addresses will be
+/// // generated three times as often as French ones.
+/// //
+/// // Note well: all semicolons in this example, even trailing semicolons, are
+/// // strictly required.
+/// faker_impl_from_weighted_generators! {
+///     Demo;
+///
+///     3 => faker_rand::en_us::internet::Email;
+///     1 => faker_rand::fr_fr::internet::Email;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("ksenger99@runolfsdottir.biz", rng.gen::<Demo>().to_string());
+/// assert_eq!("tvolkman95@heaney.info", rng.gen::<Demo>().to_string());
+/// assert_eq!("lpouros@kuhlman.info", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// Zero weights are rejected at compile time:
+///
+/// ```compile_fail
+/// use faker_rand::faker_impl_from_weighted_generators;
+///
+/// struct Demo(String);
+/// faker_impl_from_weighted_generators! {
+///     Demo;
+///
+///     1 => faker_rand::en_us::internet::Email;
+///     0 => faker_rand::fr_fr::internet::Email;
+/// }
+/// ```
+#[macro_export]
+macro_rules! faker_impl_from_weighted_generators {
+    ($name: ident; $($weight: expr => $gen: ty);+;) => {
+        $(
+            const _: () = {
+                let weight: u32 = $weight;
+                assert!(weight > 0, "generator weights must be positive");
+            };
+        )+
+
+        impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                let weights: &[u32] = &[$($weight),+];
+                let funcs: &[fn(&mut R) -> String] = &[
+                    $(
+                        |rng| rng.gen::<$gen>().to_string(),
+                    )+
+                ];
+
+                let mut n = rng.gen_range(0..weights.iter().sum::<u32>());
+                for (weight, func) in weights.iter().zip(funcs) {
+                    if n < *weight {
+                        return $name(func(rng));
+                    }
+
+                    n -= weight;
+                }
+
+                unreachable!()
+            }
+        }
+
+        $crate::__faker_impl_newtype!([] $name);
+    }
+}
+
+/// Create a generator implementation from a pattern string.
+///
+/// The first argument to the macro must be the name of type to create an
+/// implementation for. Said type must be a newtype whose first member must be a
+/// [`String`]. The second argument must be a string literal, the pattern.
+///
+/// When the generator is sampled, each `#` in the pattern is replaced with a
+/// random ASCII digit (0-9), and each `?` is replaced with a random ASCII
+/// lowercase letter (a-z). All other characters are passed through unchanged.
+/// To include a literal `#` or `?` in the output, precede it with a backslash.
+///
+/// As with the other macros in this crate, the macro will generate
+/// [`Distribution`][`rand::distributions::Distribution`],
+/// [`Display`][`std::fmt::Display`], [`AsRef<str>`],
+/// [`Deref<Target = str>`][`std::ops::Deref`], `From<T> for String`,
+/// [`Debug`], [`Clone`], [`PartialEq`], [`Eq`], and
+/// [`Hash`][`std::hash::Hash`] implementations for the type, as well as an
+/// `into_inner` method. Your newtype should therefore not derive any of these
+/// traits itself.
+///
+/// ```
+/// use faker_rand::faker_impl_from_pattern;
+///
+/// // First, declare your newtype wrapper around String.
+/// struct Demo(String);
+///
+/// // Then, invoke the macro.
+/// faker_impl_from_pattern!(Demo, r"###-??-#### \#\?");
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("677-mn-6501 #?", rng.gen::<Demo>().to_string());
+/// assert_eq!("855-kv-8948 #?", rng.gen::<Demo>().to_string());
+/// ```
+///
+/// Generators created with this macro can be used as sub-generators in
+/// templates, just like any other generator:
+///
+/// ```
+/// use faker_rand::{faker_impl_from_pattern, faker_impl_from_templates};
+/// use faker_rand::en_us::names::LastName;
+///
+/// struct TicketNumber(String);
+/// faker_impl_from_pattern!(TicketNumber, "??-####");
+///
+/// struct Demo(String);
+/// faker_impl_from_templates! {
+///     Demo;
+///
+///     "{} ({})", TicketNumber, LastName;
+/// }
+///
+/// use rand::{Rng, SeedableRng};
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("ns-5018 (Simonis)", rng.gen::<Demo>().to_string());
+/// ```
+#[macro_export]
+macro_rules! faker_impl_from_pattern {
+    ($name: ident, $pattern: expr) => {
+        impl rand::distributions::Distribution<$name> for rand::distributions::Standard {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name($crate::__expand_pattern($pattern, rng))
+            }
+        }
+
+        $crate::__faker_impl_newtype!([] $name);
+    };
+}
+
+/// Expands a pattern for [`faker_impl_from_pattern`].
+///
+/// This is an implementation detail of that macro.
+#[doc(hidden)]
+pub fn __expand_pattern<R: rand::Rng + ?Sized>(pattern: &str, rng: &mut R) -> String {
+    let mut s = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => s.push((b'0' + rng.gen_range(0..10)) as char),
+            '?' => s.push((b'a' + rng.gen_range(0..26)) as char),
+            '\\' => s.push(chars.next().unwrap_or('\\')),
+            _ => s.push(c),
+        }
+    }
+
+    s
+}
+
+/// A generator of fake data.
+///
+/// This trait is implemented for every type `T` that implements
+/// [`Display`][`std::fmt::Display`], and for which
+/// [`Standard`][`rand::distributions::Standard`] implements
+/// [`Distribution<T>`][`rand::distributions::Distribution`]. That includes all
+/// of the generators in this crate, as well as any generators you create with
+/// this crate's macros. You'll never need to implement it yourself.
+///
+/// `Generator` is useful when writing code that is generic over generators:
+///
+/// ```
+/// use faker_rand::Generator;
+/// use faker_rand::en_us::names::FirstName;
+/// use faker_rand::lorem::Word;
+///
+/// fn generate_pair<G: Generator, R: rand::Rng>(rng: &mut R) -> (String, String) {
+///     (G::generate(rng), G::generate(rng))
+/// }
+///
+/// use rand::SeedableRng;
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!(
+///     ("Michael".to_string(), "Joseph".to_string()),
+///     generate_pair::<FirstName, _>(&mut rng)
+/// );
+/// assert_eq!(
+///     ("natus".to_string(), "adipisci".to_string()),
+///     generate_pair::<Word, _>(&mut rng)
+/// );
+/// ```
+///
+/// Because [`Generator::generate`] accepts unsized RNGs, you can also call it
+/// with a `&mut dyn RngCore`. This lets you refer to generators through
+/// function pointers, and choose between generators at runtime:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use rand::RngCore;
+/// use faker_rand::Generator;
+/// use faker_rand::en_us::internet::Email;
+/// use faker_rand::en_us::names::FullName;
+///
+/// let mut registry: HashMap<&str, fn(&mut dyn RngCore) -> String> = HashMap::new();
+/// registry.insert("email", |rng| Email::generate(rng));
+/// registry.insert("name", |rng| FullName::generate(rng));
+///
+/// use rand::SeedableRng;
+/// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+///
+/// assert_eq!("Sarah Simonis DVM", registry["name"](&mut rng));
+/// assert_eq!("skyekunze@runolfsdottir.biz", registry["email"](&mut rng));
+/// ```
+pub trait Generator {
+    /// Generates a value, and returns it as a [`String`].
+    fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> String;
+}
+
+impl<T: std::fmt::Display> Generator for T
+where
+    rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+    fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> String {
+        rng.gen::<T>().to_string()
+    }
+}
+
+/// Utility generators that can be used as building blocks for larger
+/// generators.
+pub mod util {
+    /// Generates an ASCII decimal digit (0-9).
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::AsciiDigit;
+    /// assert_eq!("7", rng.gen::<AsciiDigit>().to_string());
+    /// ```
+    pub struct AsciiDigit(&'static str);
+    faker_impl_from_file!(AsciiDigit, "data/ascii_digit");
+
+    /// Generates a non-zero ASCII decimal digit (1-9).
+    ///
+    /// This is useful as the leading digit of numbers that shouldn't start with
+    /// a zero, such as building numbers.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::NonZeroAsciiDigit;
+    /// assert_eq!("7", rng.gen::<NonZeroAsciiDigit>().to_string());
+    /// ```
+    pub struct NonZeroAsciiDigit(&'static str);
+    faker_impl_from_file!(NonZeroAsciiDigit, "data/ascii_digit_nonzero");
+
+    /// Generates an ASCII lowercase letter (a-z).
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::AsciiLowercase;
+    /// assert_eq!("s", rng.gen::<AsciiLowercase>().to_string());
+    /// ```
+    pub struct AsciiLowercase(&'static str);
+    faker_impl_from_file!(AsciiLowercase, "data/ascii_lowercase");
+
+    /// Generates an ASCII uppercase letter (A-Z).
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::AsciiUppercase;
+    /// assert_eq!("S", rng.gen::<AsciiUppercase>().to_string());
+    /// ```
+    pub struct AsciiUppercase(&'static str);
+    faker_impl_from_file!(AsciiUppercase, "data/ascii_uppercase");
+
+    /// Generates an ASCII alphanumeric character (0-9, A-Z, or a-z).
+    ///
+    /// Each of the 62 possible characters is equally likely.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::AsciiAlphanumeric;
+    /// assert_eq!("h", rng.gen::<AsciiAlphanumeric>().to_string());
+    /// # let mut seen = std::collections::HashSet::new();
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<AsciiAlphanumeric>().to_string();
+    /// #     assert_eq!(1, s.len());
+    /// #     seen.insert(s);
+    /// # }
+    /// # assert_eq!(62, seen.len());
+    /// ```
+    pub struct AsciiAlphanumeric(&'static str);
+    faker_impl_from_file!(AsciiAlphanumeric, "data/ascii_alphanumeric");
+
+    /// Generates an ASCII digit or lowercase letter (0-9 or a-z).
+    ///
+    /// Each of the 36 possible characters is equally likely. This is unlike
+    /// [`Either<AsciiDigit, AsciiLowercase>`](Either), which would generate a
+    /// digit half of the time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::AsciiAlphanumericLowercase;
+    /// assert_eq!("p", rng.gen::<AsciiAlphanumericLowercase>().to_string());
+    /// # let mut seen = std::collections::HashSet::new();
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<AsciiAlphanumericLowercase>().to_string();
+    /// #     assert_eq!(1, s.len());
+    /// #     assert!(s.bytes().all(|b| b.is_ascii_digit() || b.is_ascii_lowercase()));
+    /// #     seen.insert(s);
+    /// # }
+    /// # assert_eq!(36, seen.len());
+    /// ```
+    pub struct AsciiAlphanumericLowercase(&'static str);
+    faker_impl_from_file!(AsciiAlphanumericLowercase, "data/ascii_alphanumeric_lower");
+
+    /// Generates a lowercase hexadecimal digit (0-9 or a-f).
+    ///
+    /// For uppercase digits, use [`HexDigitUpper`]. It generates the same
+    /// sequence of digits as [`ToUppercase<HexDigit>`](ToUppercase), but
+    /// without the extra work of converting each digit.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::HexDigit;
+    /// assert_eq!("b", rng.gen::<HexDigit>().to_string());
+    /// ```
+    ///
+    /// `HexDigit` is handy in templates, for instance to make hash fragments:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::util::HexDigit;
+    ///
+    /// struct Demo(String);
+    /// faker_impl_from_templates! {
+    ///     Demo;
+    ///
+    ///     "{}{}:{}{}", HexDigit, HexDigit, HexDigit, HexDigit;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("be:d2", rng.gen::<Demo>().to_string());
+    /// ```
+    pub struct HexDigit(&'static str);
+    faker_impl_from_file!(HexDigit, "data/hex_digit_lower");
+
+    /// Generates an uppercase hexadecimal digit (0-9 or A-F).
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::HexDigitUpper;
+    /// assert_eq!("B", rng.gen::<HexDigitUpper>().to_string());
+    ///
+    /// // HexDigitUpper is equivalent to ToUppercase<HexDigit>.
+    /// use faker_rand::util::{HexDigit, ToUppercase};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// assert_eq!("B", rng.gen::<ToUppercase<HexDigit>>().to_string());
+    /// # let mut upper_rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+    /// # let mut wrapped_rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+    /// # for _ in 0..1000 {
+    /// #     assert_eq!(
+    /// #         upper_rng.gen::<HexDigitUpper>().to_string(),
+    /// #         wrapped_rng.gen::<ToUppercase<HexDigit>>().to_string()
+    /// #     );
+    /// # }
+    /// ```
+    pub struct HexDigitUpper(&'static str);
+    faker_impl_from_file!(HexDigitUpper, "data/hex_digit_upper");
+
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+    use std::collections::HashSet;
+    use std::marker::PhantomData;
+    use std::sync::Mutex;
+
+    /// Checks a `PERCENT` parameter, at compile time when used to define an
+    /// associated constant.
+    const fn assert_percent(percent: u32) {
+        assert!(percent <= 100, "PERCENT must be no greater than 100");
+    }
+
+    /// Generates a boolean, displayed as `true` or `false`.
+    ///
+    /// The output is `true` with a probability of `PERCENT`%, which defaults to
+    /// 50%. `PERCENT` must be no greater than 100, which is checked at compile
+    /// time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::Boolean;
+    /// assert_eq!("false", rng.gen::<Boolean>().to_string());
+    /// assert_eq!("true", rng.gen::<Boolean>().to_string());
+    /// assert_eq!("true", rng.gen::<Boolean<90>>().to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::Boolean;
+    /// rng.gen::<Boolean<101>>();
+    /// ```
+    pub struct Boolean<const PERCENT: u32 = 50>(String);
+
+    impl<const PERCENT: u32> Boolean<PERCENT> {
+        const ASSERT_VALID: () = assert_percent(PERCENT);
+    }
+
+    impl<const PERCENT: u32> Distribution<Boolean<PERCENT>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Boolean<PERCENT> {
+            let () = Boolean::<PERCENT>::ASSERT_VALID;
+
+            Boolean(rng.gen_ratio(PERCENT, 100).to_string())
+        }
+    }
+
+    __faker_impl_newtype!([const PERCENT: u32] Boolean<PERCENT>);
+
+    /// Generates a boolean, displayed as `yes` or `no`.
+    ///
+    /// The output is `yes` with a probability of `PERCENT`%, which defaults to
+    /// 50%. `PERCENT` must be no greater than 100, which is checked at compile
+    /// time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::YesNo;
+    /// assert_eq!("no", rng.gen::<YesNo>().to_string());
+    /// assert_eq!("yes", rng.gen::<YesNo>().to_string());
+    /// assert_eq!("yes", rng.gen::<YesNo<90>>().to_string());
+    /// ```
+    pub struct YesNo<const PERCENT: u32 = 50>(String);
+
+    impl<const PERCENT: u32> YesNo<PERCENT> {
+        const ASSERT_VALID: () = assert_percent(PERCENT);
+    }
+
+    impl<const PERCENT: u32> Distribution<YesNo<PERCENT>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> YesNo<PERCENT> {
+            let () = YesNo::<PERCENT>::ASSERT_VALID;
+
+            let s = if rng.gen_ratio(PERCENT, 100) {
+                "yes"
+            } else {
+                "no"
+            };
+            YesNo(s.to_string())
+        }
+    }
+
+    __faker_impl_newtype!([const PERCENT: u32] YesNo<PERCENT>);
+
+    /// Generates an integer between `MIN` and `MAX`, inclusive, in decimal
+    /// notation.
+    ///
+    /// Each integer in the range is equally likely. Unlike concatenating
+    /// [`AsciiDigit`]s, the output never has leading zeros. To get fixed-width
+    /// output instead, wrap this generator in [`ZeroPad`]. `MIN` must be no
+    /// greater than `MAX`, which is checked at compile time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::NumberInRange;
+    /// assert_eq!("76", rng.gen::<NumberInRange<18, 99>>().to_string());
+    /// assert_eq!("6", rng.gen::<NumberInRange<1, 12>>().to_string());
+    /// # use std::collections::HashSet;
+    /// # let mut seen = HashSet::new();
+    /// # for _ in 0..1000 {
+    /// #     let n = rng.gen::<NumberInRange<1, 3>>().to_string();
+    /// #     seen.insert(n.parse::<u64>().unwrap());
+    /// #
+    /// #     let n = rng.gen::<NumberInRange<0, 9999>>().to_string();
+    /// #     assert!(n == "0" || !n.starts_with('0'));
+    /// # }
+    /// # assert_eq!(seen, HashSet::from([1, 2, 3]));
+    /// # assert_eq!("5", rng.gen::<NumberInRange<5, 5>>().to_string());
+    /// ```
+    ///
+    /// `NumberInRange` is handy in templates:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::util::NumberInRange;
+    ///
+    /// struct Demo(String);
+    /// faker_impl_from_templates! {
+    ///     Demo;
+    ///
+    ///     "{} Main Street", NumberInRange<1, 9999>;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("8791 Main Street", rng.gen::<Demo>().to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::NumberInRange;
+    /// rng.gen::<NumberInRange<10, 1>>();
+    /// ```
+    pub struct NumberInRange<const MIN: u64, const MAX: u64>(String);
+
+    impl<const MIN: u64, const MAX: u64> NumberInRange<MIN, MAX> {
+        const ASSERT_VALID: () = assert!(MIN <= MAX, "MIN must be no greater than MAX");
+    }
+
+    impl<const MIN: u64, const MAX: u64> Distribution<NumberInRange<MIN, MAX>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NumberInRange<MIN, MAX> {
+            let () = NumberInRange::<MIN, MAX>::ASSERT_VALID;
+
+            NumberInRange(rng.gen_range(MIN..=MAX).to_string())
+        }
+    }
+
+    __faker_impl_newtype!([const MIN: u64, const MAX: u64] NumberInRange<MIN, MAX>);
+
+    /// Generates a semantic version, such as `1.4.2` or `0.3.0-beta.1`.
+    ///
+    /// The major, minor, and patch versions are small non-negative integers.
+    /// About a quarter of versions have a pre-release suffix, such as `-rc.2`,
+    /// and about a tenth have build metadata, such as `+build.417`. Every
+    /// generated version is valid according to the [Semantic Versioning
+    /// 2.0.0](https://semver.org) grammar.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::SemVer;
+    /// assert_eq!("6.14.21", rng.gen::<SemVer>().to_string());
+    /// assert_eq!("0.17.17", rng.gen::<SemVer>().to_string());
+    /// assert_eq!("9.16.27", rng.gen::<SemVer>().to_string());
+    /// # fn is_numeric(s: &str) -> bool {
+    /// #     !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) && (s == "0" || !s.starts_with('0'))
+    /// # }
+    /// # fn is_identifier(s: &str) -> bool {
+    /// #     !s.is_empty() && s.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    /// # }
+    /// # let (mut pre_releases, mut builds) = (0, 0);
+    /// # for _ in 0..10000 {
+    /// #     let version = rng.gen::<SemVer>().to_string();
+    /// #     let (rest, build) = match version.split_once('+') {
+    /// #         Some((rest, build)) => (rest, Some(build)),
+    /// #         None => (version.as_str(), None),
+    /// #     };
+    /// #     let (core, pre_release) = match rest.split_once('-') {
+    /// #         Some((core, pre_release)) => (core, Some(pre_release)),
+    /// #         None => (rest, None),
+    /// #     };
+    /// #     let core: Vec<_> = core.split('.').collect();
+    /// #     assert!(core.len() == 3 && core.iter().all(|n| is_numeric(n)), "{}", version);
+    /// #     if let Some(pre_release) = pre_release {
+    /// #         pre_releases += 1;
+    /// #         for id in pre_release.split('.') {
+    /// #             assert!(is_identifier(id), "{}", version);
+    /// #             assert!(!id.bytes().all(|b| b.is_ascii_digit()) || is_numeric(id), "{}", version);
+    /// #         }
+    /// #     }
+    /// #     if let Some(build) = build {
+    /// #         builds += 1;
+    /// #         assert!(build.split('.').all(is_identifier), "{}", version);
+    /// #     }
+    /// # }
+    /// # assert!((2000..3000).contains(&pre_releases), "{}", pre_releases);
+    /// # assert!((500..1500).contains(&builds), "{}", builds);
+    /// ```
+    pub struct SemVer(String);
+
+    impl Distribution<SemVer> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SemVer {
+            let mut s = format!(
+                "{}.{}.{}",
+                rng.gen_range(0..10),
+                rng.gen_range(0..20),
+                rng.gen_range(0..30)
+            );
+
+            if rng.gen_ratio(1, 4) {
+                let label = ["alpha", "beta", "rc"][rng.gen_range(0..3)];
+                s.push_str(&format!("-{}.{}", label, rng.gen_range(0..10)));
+            }
+
+            if rng.gen_ratio(1, 10) {
+                s.push_str(&format!("+build.{}", rng.gen_range(1..1000)));
+            }
+
+            SemVer(s)
+        }
+    }
+
+    __faker_impl_newtype!([] SemVer);
+
+    /// Generates a common HTTP status code, such as "404".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::HttpStatusCode;
+    /// assert_eq!("422", rng.gen::<HttpStatusCode>().to_string());
+    /// assert_eq!("401", rng.gen::<HttpStatusCode>().to_string());
+    /// ```
+    pub struct HttpStatusCode(String);
+    faker_impl_from_csv!(HttpStatusCode, "data/http_statuses.csv", "{0}");
+
+    /// Generates the reason phrase of a common HTTP status code, such as "Not
+    /// Found".
+    ///
+    /// The reason phrases are those of [`HttpStatusCode`], but are chosen
+    /// independently of it. To generate a code along with its reason phrase,
+    /// use [`HttpStatus`] instead.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::HttpStatusReason;
+    /// assert_eq!("Unprocessable Content", rng.gen::<HttpStatusReason>().to_string());
+    /// assert_eq!("Unauthorized", rng.gen::<HttpStatusReason>().to_string());
+    /// ```
+    pub struct HttpStatusReason(String);
+    faker_impl_from_csv!(HttpStatusReason, "data/http_statuses.csv", "{1}");
+
+    /// Generates a common HTTP status code followed by its reason phrase, such
+    /// as "404 Not Found".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::HttpStatus;
+    /// assert_eq!("422 Unprocessable Content", rng.gen::<HttpStatus>().to_string());
+    /// assert_eq!("401 Unauthorized", rng.gen::<HttpStatus>().to_string());
+    /// ```
+    pub struct HttpStatus(String);
+    faker_impl_from_csv!(HttpStatus, "data/http_statuses.csv", "{0} {1}");
+
+    /// Wraps a string generator so that its output is all ASCII lowercase
+    /// letters (a-z).
+    ///
+    /// Any character that can't be lowercased to ASCII lowercase is stripped
+    /// from the output.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// // FirstName generates strings whose first letter is capitalized ...
+    /// use faker_rand::en_us::names::FirstName;
+    /// assert_eq!("Michael", rng.gen::<FirstName>().to_string());
+    ///
+    /// // ... But with ToAsciiLowercase, it's lowercased.
+    /// use faker_rand::util::ToAsciiLowercase;
+    /// assert_eq!("joseph", rng.gen::<ToAsciiLowercase<FirstName>>().to_string());
+    /// ```
+    ///
+    /// Like every generator, if this crate's `serde` feature is enabled,
+    /// `ToAsciiLowercase` serializes as a plain string, rather than as a struct:
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::names::FirstName;
+    /// use faker_rand::util::ToAsciiLowercase;
+    /// let name: ToAsciiLowercase<FirstName> = rng.gen();
+    /// assert_eq!(r#""michael""#, serde_json::to_string(&name).unwrap());
+    /// # }
+    /// ```
+    pub struct ToAsciiLowercase<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<ToAsciiLowercase<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ToAsciiLowercase<T> {
+            let mut s = deunicode::deunicode(&rng.gen::<T>().to_string()).to_lowercase();
+            s.retain(|c| c.is_ascii_lowercase());
+
+            ToAsciiLowercase(s, std::marker::PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] ToAsciiLowercase<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is all ASCII uppercase
+    /// letters (A-Z).
+    ///
+    /// Any character that can't be uppercased to ASCII uppercase is stripped
+    /// from the output. This is the uppercase equivalent of
+    /// [`ToAsciiLowercase`].
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// use faker_rand::util::ToAsciiUppercase;
+    /// assert_eq!("MLLEGISELEMARTINEZ", rng.gen::<ToAsciiUppercase<FullName>>().to_string());
+    /// ```
+    pub struct ToAsciiUppercase<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<ToAsciiUppercase<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ToAsciiUppercase<T> {
+            let mut s = deunicode::deunicode(&rng.gen::<T>().to_string()).to_uppercase();
+            s.retain(|c| c.is_ascii_uppercase());
+
+            ToAsciiUppercase(s, std::marker::PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] ToAsciiUppercase<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is uppercased.
+    ///
+    /// Unlike [`ToAsciiUppercase`], this uses Unicode case mapping, and so
+    /// doesn't strip any characters from the output. Accented letters remain
+    /// accented.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// use faker_rand::util::ToUppercase;
+    /// assert_eq!("MLLE GISÈLE MARTINEZ", rng.gen::<ToUppercase<FullName>>().to_string());
+    /// ```
+    pub struct ToUppercase<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<ToUppercase<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ToUppercase<T> {
+            ToUppercase(rng.gen::<T>().to_string().to_uppercase(), PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] ToUppercase<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is lowercased.
+    ///
+    /// This is the lowercase equivalent of [`ToUppercase`]. Unlike
+    /// [`ToAsciiLowercase`], this uses Unicode case mapping, and so doesn't
+    /// strip any characters from the output. Accented letters remain accented,
+    /// and spaces and punctuation are kept.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// use faker_rand::util::ToLowercase;
+    /// assert_eq!("mlle gisèle martinez", rng.gen::<ToLowercase<FullName>>().to_string());
+    /// ```
+    pub struct ToLowercase<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<ToLowercase<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ToLowercase<T> {
+            ToLowercase(rng.gen::<T>().to_string().to_lowercase(), PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] ToLowercase<T>, PhantomData);
+
+    /// Wraps a string generator so that the first letter of its output is
+    /// capitalized.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// // Word generates strings that are all lowercase ...
+    /// use faker_rand::lorem::Word;
+    /// assert_eq!("impedit", rng.gen::<Word>().to_string());
+    ///
+    /// // ... But with CapitalizeFirstLetter, the first letter is capitalized.
+    /// use faker_rand::util::CapitalizeFirstLetter;
+    /// assert_eq!("Totam", rng.gen::<CapitalizeFirstLetter<Word>>().to_string());
+    /// ```
+    pub struct CapitalizeFirstLetter<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<CapitalizeFirstLetter<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CapitalizeFirstLetter<T> {
+            let s = rng.gen::<T>().to_string();
+            let mut c = s.chars();
+
+            CapitalizeFirstLetter(
+                c.next().unwrap().to_uppercase().chain(c).collect(),
+                std::marker::PhantomData,
+            )
+        }
+    }
+
+    __faker_impl_newtype!([T] CapitalizeFirstLetter<T>, PhantomData);
+
+    /// Wraps a string generator so that the first letter of each word in its
+    /// output is capitalized, and the remaining letters are lowercased.
+    ///
+    /// Words are separated by whitespace or hyphens. Separators are kept as-is,
+    /// so consecutive separators are preserved in the output.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Sentence;
+    /// use faker_rand::util::TitleCase;
+    /// assert_eq!(
+    ///     "Debitis Recusandae Aut Sequi Aut Assumenda Cupiditate.",
+    ///     rng.gen::<TitleCase<Sentence>>().to_string()
+    /// );
+    ///
+    /// // Letters after the first one in each word are lowercased.
+    /// use faker_rand::fr_fr::names::FullName;
+    /// assert_eq!("M Guyot Durand", rng.gen::<TitleCase<FullName>>().to_string());
+    /// ```
+    pub struct TitleCase<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<TitleCase<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TitleCase<T> {
+            let mut s = String::new();
+            let mut word_start = true;
+            for c in rng.gen::<T>().to_string().chars() {
+                if word_start {
+                    s.extend(c.to_uppercase());
+                } else {
+                    s.extend(c.to_lowercase());
+                }
+
+                word_start = c.is_whitespace() || c == '-';
+            }
+
+            TitleCase(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] TitleCase<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is a URL-friendly "slug".
+    ///
+    /// The output is converted to ASCII and lowercased, and each run of
+    /// characters other than ASCII letters and digits is replaced with a single
+    /// hyphen. The output never starts or ends with a hyphen, so it's either
+    /// empty or matches the regex `^[a-z0-9]+(-[a-z0-9]+)*$`.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Sentence;
+    /// use faker_rand::util::Slugify;
+    /// assert_eq!(
+    ///     "debitis-recusandae-aut-sequi-aut-assumenda-cupiditate",
+    ///     rng.gen::<Slugify<Sentence>>().to_string()
+    /// );
+    ///
+    /// use faker_rand::en_us::company::CompanyName;
+    /// assert_eq!("runolfsdottir-dickens", rng.gen::<Slugify<CompanyName>>().to_string());
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// assert_eq!("prof-aloyse-pierre", rng.gen::<Slugify<FullName>>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     for slug in [
+    /// #         rng.gen::<Slugify<Sentence>>().to_string(),
+    /// #         rng.gen::<Slugify<CompanyName>>().to_string(),
+    /// #         rng.gen::<Slugify<FullName>>().to_string(),
+    /// #     ] {
+    /// #         assert!(slug.split('-').all(|part| {
+    /// #             !part.is_empty()
+    /// #                 && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    /// #         }));
+    /// #     }
+    /// # }
+    /// ```
+    pub struct Slugify<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<Slugify<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Slugify<T> {
+            let text = deunicode::deunicode(&rng.gen::<T>().to_string()).to_lowercase();
+
+            let mut s = String::new();
+            for part in text.split(|c: char| !c.is_ascii_alphanumeric()) {
+                if part.is_empty() {
+                    continue;
+                }
+
+                if !s.is_empty() {
+                    s.push('-');
+                }
+
+                s.push_str(part);
+            }
+
+            Slugify(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] Slugify<T>, PhantomData);
+
+    /// Wraps a string generator so that all whitespace is removed from its
+    /// output.
+    ///
+    /// This removes spaces, tabs, and newlines alike, so it works on multi-line
+    /// generators such as [`Address`](crate::en_us::addresses::Address) too.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::names::FullName;
+    /// use faker_rand::util::StripWhitespace;
+    /// assert_eq!("SarahSimonisDVM", rng.gen::<StripWhitespace<FullName>>().to_string());
+    ///
+    /// use faker_rand::en_us::addresses::Address;
+    /// assert_eq!("Ms.LindaBechtelarV685SusanVilleMarvinborough,MA01179-0657", rng.gen::<StripWhitespace<Address>>().to_string());
+    /// ```
+    ///
+    /// `StripWhitespace` can also be used inside templates, for instance to
+    /// make handles out of names:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::en_us::names::FullName;
+    /// use faker_rand::util::{AsciiDigit, StripWhitespace};
+    ///
+    /// struct Handle(String);
+    /// faker_impl_from_templates! {
+    ///     Handle;
+    ///
+    ///     "@{}{}", StripWhitespace<FullName>, AsciiDigit;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("@SarahSimonisDVM8", rng.gen::<Handle>().to_string());
+    /// ```
+    pub struct StripWhitespace<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<StripWhitespace<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> StripWhitespace<T> {
+            let mut s = rng.gen::<T>().to_string();
+            s.retain(|c| !c.is_whitespace());
+
+            StripWhitespace(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T] StripWhitespace<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is at most `N` characters
+    /// long.
+    ///
+    /// Longer outputs are cut off after their `N`th character. Lengths are
+    /// measured in [`char`]s, so a multi-byte character is never split.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// use faker_rand::util::Truncate;
+    /// assert_eq!("Mlle Gisè", rng.gen::<Truncate<FullName, 9>>().to_string());
+    ///
+    /// // Outputs that are already short enough are left as-is.
+    /// assert_eq!("Mlle Pécine BRUN", rng.gen::<Truncate<FullName, 50>>().to_string());
+    /// ```
+    pub struct Truncate<T, const N: usize>(String, PhantomData<T>);
+
+    impl<T: ToString, const N: usize> Distribution<Truncate<T, N>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Truncate<T, N> {
+            let mut s = rng.gen::<T>().to_string();
+            if let Some((i, _)) = s.char_indices().nth(N) {
+                s.truncate(i);
+            }
+
+            Truncate(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const N: usize] Truncate<T, N>, PhantomData);
+
+    /// Wraps a string generator so that its output is at most `N` characters
+    /// long, preferring to cut it off between words.
+    ///
+    /// This is like [`Truncate`], except that longer outputs are cut off at the
+    /// last whitespace that keeps them within `N` characters, and any trailing
+    /// whitespace or punctuation is then removed. If the first word is itself
+    /// longer than `N` characters, it's cut off after its `N`th character
+    /// instead. Any leading whitespace is removed first, and doesn't count
+    /// towards `N`.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::company::CompanyName;
+    /// use faker_rand::util::TruncateWords;
+    /// assert_eq!("Konopelski", rng.gen::<TruncateWords<CompanyName, 15>>().to_string());
+    ///
+    /// use faker_rand::fr_fr::names::FullName;
+    /// assert_eq!("Hardouin", rng.gen::<TruncateWords<FullName, 9>>().to_string());
+    ///
+    /// // Prefixed adds a leading space, which is removed.
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Prefixed;
+    /// assert_eq!("dol", rng.gen::<TruncateWords<Prefixed<Word>, 3>>().to_string());
+    /// ```
+    pub struct TruncateWords<T, const N: usize>(String, PhantomData<T>);
+
+    impl<T: ToString, const N: usize> Distribution<TruncateWords<T, N>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TruncateWords<T, N> {
+            let s = rng.gen::<T>().to_string();
+            let mut s = s.trim_start().to_string();
+            if let Some((i, _)) = s.char_indices().nth(N) {
+                // The character just past the limit may itself be whitespace,
+                // in which case the cut can be made right before it.
+                let limit = i + s[i..].chars().next().map_or(0, char::len_utf8);
+                match s[..limit].rfind(char::is_whitespace) {
+                    Some(j) => {
+                        let len = s[..j]
+                            .trim_end_matches(|c: char| {
+                                c.is_whitespace() || c.is_ascii_punctuation()
+                            })
+                            .len();
+                        s.truncate(len);
+                    }
+                    None => s.truncate(i),
+                }
+            }
+
+            TruncateWords(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const N: usize] TruncateWords<T, N>, PhantomData);
+
+    /// Wraps a string generator so that its output is reversed.
+    ///
+    /// The output is reversed [`char`] by [`char`], so multi-byte characters
+    /// are kept intact.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Reverse;
+    /// assert_eq!("tidepmi", rng.gen::<Reverse<Word>>().to_string());
+    ///
+    /// // This would generate "Mlle Gisèle MARTINEZ" without Reverse.
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// use faker_rand::fr_fr::names::FullName;
+    /// assert_eq!("ZENITRAM elèsiG ellM", rng.gen::<Reverse<FullName>>().to_string());
+    /// ```
+    pub struct Reverse<T>(String, PhantomData<T>);
+
+    impl<T: ToString> Distribution<Reverse<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Reverse<T> {
+            Reverse(
+                rng.gen::<T>().to_string().chars().rev().collect(),
+                PhantomData,
+            )
+        }
+    }
+
+    __faker_impl_newtype!([T] Reverse<T>, PhantomData);
+
+    /// Wraps a string generator so that its output is left-padded with zeros
+    /// to at least `WIDTH` characters.
+    ///
+    /// Outputs that are already `WIDTH` characters or longer are passed through
+    /// unchanged; they are never truncated. To cap the length of the output,
+    /// see [`Truncate`].
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::{NumberInRange, ZeroPad};
+    /// assert_eq!("000709", rng.gen::<ZeroPad<NumberInRange<1, 999>, 6>>().to_string());
+    ///
+    /// // Outputs already at the target width are left as-is ...
+    /// assert_eq!("5193", rng.gen::<ZeroPad<NumberInRange<1000, 9999>, 4>>().to_string());
+    ///
+    /// // ... and so are outputs longer than it.
+    /// assert_eq!("154154", rng.gen::<ZeroPad<NumberInRange<100000, 999999>, 4>>().to_string());
+    ///
+    /// use faker_rand::util::{AsciiDigit, Repeat};
+    /// assert_eq!("000589", rng.gen::<ZeroPad<Repeat<AsciiDigit, 3>, 6>>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     assert_eq!(6, rng.gen::<ZeroPad<NumberInRange<0, 999999>, 6>>().to_string().len());
+    /// # }
+    /// ```
+    pub struct ZeroPad<T, const WIDTH: usize>(String, PhantomData<T>);
+
+    impl<T: ToString, const WIDTH: usize> Distribution<ZeroPad<T, WIDTH>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ZeroPad<T, WIDTH> {
+            let s = rng.gen::<T>().to_string();
+            ZeroPad(format!("{:0>width$}", s, width = WIDTH), PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const WIDTH: usize] ZeroPad<T, WIDTH>, PhantomData);
+
+    /// Wraps a string generator, concatenating the output of `N` samples from
+    /// it.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::{AsciiDigit, Repeat};
+    /// assert_eq!("705898153", rng.gen::<Repeat<AsciiDigit, 9>>().to_string());
+    ///
+    /// // Repeating zero times results in an empty string.
+    /// assert_eq!("", rng.gen::<Repeat<AsciiDigit, 0>>().to_string());
+    /// ```
+    pub struct Repeat<T, const N: usize>(String, PhantomData<T>);
+
+    impl<T: ToString, const N: usize> Distribution<Repeat<T, N>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Repeat<T, N> {
+            let mut s = String::new();
+            for _ in 0..N {
+                s.push_str(&rng.gen::<T>().to_string());
+            }
+
+            Repeat(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const N: usize] Repeat<T, N>, PhantomData);
+
+    /// Wraps a string generator, joining the output of `N` samples from it with
+    /// the separator `SEP`, which defaults to a space.
+    ///
+    /// There is no separator before the first sample or after the last one.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::{HexDigit, Join, Repeat};
+    /// assert_eq!(
+    ///     "b7:be:d2:ec:8c:a3",
+    ///     rng.gen::<Join<Repeat<HexDigit, 2>, 6, ':'>>().to_string()
+    /// );
+    ///
+    /// use faker_rand::lorem::Word;
+    /// assert_eq!("et animi necessitatibus", rng.gen::<Join<Word, 3>>().to_string());
+    /// assert_eq!("fugiat,voluptas,facere", rng.gen::<Join<Word, 3, ','>>().to_string());
+    /// assert_eq!("repudiandae\na\nut", rng.gen::<Join<Word, 3, '\n'>>().to_string());
+    /// ```
+    ///
+    /// If this crate's `serde` feature is enabled, `Join` serializes as a single
+    /// string, rather than as a list of its samples:
+    ///
+    /// ```
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Join;
+    /// let words: Join<Word, 3, ','> = rng.gen();
+    /// assert_eq!(r#""impedit,totam,cumque""#, serde_json::to_string(&words).unwrap());
+    /// # }
+    /// ```
+    ///
+    /// `SEP` is a single [`char`]. For a separator made up of several
+    /// characters, like `", "`, use [`JoinWith`] instead.
+    pub struct Join<T, const N: usize, const SEP: char = ' '>(String, PhantomData<T>);
+
+    impl<T: ToString, const N: usize, const SEP: char> Distribution<Join<T, N, SEP>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Join<T, N, SEP> {
+            let mut s = String::new();
+            for i in 0..N {
+                if i > 0 {
+                    s.push(SEP);
+                }
+
+                s.push_str(&rng.gen::<T>().to_string());
+            }
+
+            Join(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const N: usize, const SEP: char] Join<T, N, SEP>, PhantomData);
+
+    /// Wraps a string generator, joining the output of between `MIN` and `MAX`
+    /// (inclusive) samples from it with the separator `SEP`, which defaults to
+    /// a space.
+    ///
+    /// This is like [`Join`], except that the number of samples is chosen
+    /// uniformly at random each time. `MIN` must be no greater than `MAX`,
+    /// which is checked at compile time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::JoinRange;
+    /// assert_eq!(
+    ///     "totam cumque debitis unde eum recusandae aut sequi aut assumenda",
+    ///     rng.gen::<JoinRange<Word, 3, 12>>().to_string()
+    /// );
+    /// assert_eq!(
+    ///     "aliquid voluptas facilis consectetur et animi necessitatibus fugiat",
+    ///     rng.gen::<JoinRange<Word, 3, 12>>().to_string()
+    /// );
+    /// assert_eq!(
+    ///     "facere,repudiandae,a",
+    ///     rng.gen::<JoinRange<Word, 1, 3, ','>>().to_string()
+    /// );
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::JoinRange;
+    /// rng.gen::<JoinRange<Word, 3, 2>>();
+    /// ```
+    pub struct JoinRange<T, const MIN: usize, const MAX: usize, const SEP: char = ' '>(
+        String,
+        PhantomData<T>,
+    );
+
+    impl<T, const MIN: usize, const MAX: usize, const SEP: char> JoinRange<T, MIN, MAX, SEP> {
+        const ASSERT_VALID: () = assert!(MIN <= MAX, "MIN must be no greater than MAX");
+    }
+
+    impl<T: ToString, const MIN: usize, const MAX: usize, const SEP: char>
+        Distribution<JoinRange<T, MIN, MAX, SEP>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> JoinRange<T, MIN, MAX, SEP> {
+            let () = JoinRange::<T, MIN, MAX, SEP>::ASSERT_VALID;
+
+            let n = rng.gen_range(MIN..=MAX);
+
+            let mut s = String::new();
+            for i in 0..n {
+                if i > 0 {
+                    s.push(SEP);
+                }
+
+                s.push_str(&rng.gen::<T>().to_string());
+            }
+
+            JoinRange(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!(
+        [T, const MIN: usize, const MAX: usize, const SEP: char]
+        JoinRange<T, MIN, MAX, SEP>,
+        PhantomData
+    );
+
+    /// A separator for [`JoinWith`] to put between samples.
+    ///
+    /// This crate provides [`CommaSpace`], [`Space`], and [`Newline`]. To use
+    /// some other separator, implement this trait on a type of your own:
+    ///
+    /// ```
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::{JoinWith, Separator};
+    ///
+    /// struct Arrow;
+    /// impl Separator for Arrow {
+    ///     const SEPARATOR: &'static str = " -> ";
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("impedit -> totam -> cumque", rng.gen::<JoinWith<Word, 3, Arrow>>().to_string());
+    /// ```
+    pub trait Separator {
+        /// The separator.
+        const SEPARATOR: &'static str;
+    }
+
+    /// A [`Separator`] made up of a comma and a space, as in `"a, b, c"`.
+    pub struct CommaSpace;
+
+    impl Separator for CommaSpace {
+        const SEPARATOR: &'static str = ", ";
+    }
+
+    /// A [`Separator`] made up of a single space, as in `"a b c"`.
+    pub struct Space;
+
+    impl Separator for Space {
+        const SEPARATOR: &'static str = " ";
+    }
+
+    /// A [`Separator`] made up of a single newline, as in `"a\nb\nc"`.
+    pub struct Newline;
+
+    impl Separator for Newline {
+        const SEPARATOR: &'static str = "\n";
+    }
+
+    /// Wraps a string generator, joining the output of `N` samples from it with
+    /// the [`Separator`] `Sep`.
+    ///
+    /// This is like [`Join`], except that the separator can be any string,
+    /// rather than a single [`char`].
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::{CommaSpace, JoinWith, Newline};
+    /// assert_eq!("impedit, totam, cumque", rng.gen::<JoinWith<Word, 3, CommaSpace>>().to_string());
+    /// assert_eq!("debitis\nunde\neum", rng.gen::<JoinWith<Word, 3, Newline>>().to_string());
+    /// ```
+    ///
+    /// It can be used in templates, like any other generator:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::{CommaSpace, JoinWith};
+    ///
+    /// struct Tags(String);
+    /// faker_impl_from_templates! {
+    ///     Tags;
+    ///
+    ///     "Tags: {}", JoinWith<Word, 3, CommaSpace>;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("Tags: cumque, debitis, unde", rng.gen::<Tags>().to_string());
+    /// ```
+    pub struct JoinWith<T, const N: usize, Sep>(String, PhantomData<(T, Sep)>);
+
+    impl<T: ToString, const N: usize, Sep: Separator> Distribution<JoinWith<T, N, Sep>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> JoinWith<T, N, Sep> {
+            let mut s = String::new();
+            for i in 0..N {
+                if i > 0 {
+                    s.push_str(Sep::SEPARATOR);
+                }
+
+                s.push_str(&rng.gen::<T>().to_string());
+            }
+
+            JoinWith(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const N: usize, Sep] JoinWith<T, N, Sep>, PhantomData);
+
+    /// Wraps a string generator so that its output is present only some of the
+    /// time, and is otherwise empty.
+    ///
+    /// The output of the wrapped generator is used with a probability of
+    /// `PERCENT`%, which defaults to 50%. `PERCENT` must be no greater than
+    /// 100, which is checked at compile time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Optional;
+    /// assert_eq!("", rng.gen::<Optional<Word>>().to_string());
+    /// assert_eq!("cumque", rng.gen::<Optional<Word>>().to_string());
+    /// assert_eq!("debitis", rng.gen::<Optional<Word>>().to_string());
+    ///
+    /// // With a PERCENT of 10, the output is usually empty.
+    /// assert_eq!("", rng.gen::<Optional<Word, 10>>().to_string());
+    /// assert_eq!("", rng.gen::<Optional<Word, 10>>().to_string());
+    /// assert_eq!("", rng.gen::<Optional<Word, 10>>().to_string());
+    /// ```
+    ///
+    /// When using `Optional` in a template, be careful not to put a separator
+    /// next to it in the template string, as the separator would remain even
+    /// when the output of `Optional` is empty. Instead, wrap the generator in
+    /// [`Prefixed`], so that the separator is part of the optional output:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::en_us::names::{FirstName, LastName, NameSuffix};
+    /// use faker_rand::util::{Optional, Prefixed};
+    ///
+    /// struct Demo(String);
+    /// faker_impl_from_templates! {
+    ///     Demo;
+    ///
+    ///     // Not "{} {} {}", which would leave a trailing space when there's no
+    ///     // suffix.
+    ///     "{} {}{}", FirstName, LastName, Optional<Prefixed<NameSuffix>>;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("Joseph MacGyver V", rng.gen::<Demo>().to_string());
+    /// assert_eq!("Reyes Kunde", rng.gen::<Demo>().to_string());
+    /// assert_eq!("Sarah Russel", rng.gen::<Demo>().to_string());
+    /// assert_eq!("Lacy Beatty", rng.gen::<Demo>().to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Optional;
+    /// rng.gen::<Optional<Word, 101>>();
+    /// ```
+    pub struct Optional<T, const PERCENT: u32 = 50>(String, PhantomData<T>);
+
+    impl<T, const PERCENT: u32> Optional<T, PERCENT> {
+        const ASSERT_VALID: () = assert_percent(PERCENT);
+    }
+
+    impl<T: ToString, const PERCENT: u32> Distribution<Optional<T, PERCENT>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Optional<T, PERCENT> {
+            let () = Optional::<T, PERCENT>::ASSERT_VALID;
+
+            let s = if rng.gen_ratio(PERCENT, 100) {
+                rng.gen::<T>().to_string()
+            } else {
+                String::new()
+            };
+
+            Optional(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const PERCENT: u32] Optional<T, PERCENT>, PhantomData);
+
+    /// Wraps a string generator so that its output is preceded by the separator
+    /// `SEP`, which defaults to a space.
+    ///
+    /// This is mostly useful in combination with [`Optional`], to make a
+    /// separator appear only when the optional output is present.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::util::Prefixed;
+    /// assert_eq!(" impedit", rng.gen::<Prefixed<Word>>().to_string());
+    /// assert_eq!("-totam", rng.gen::<Prefixed<Word, '-'>>().to_string());
+    /// ```
+    pub struct Prefixed<T, const SEP: char = ' '>(String, PhantomData<T>);
+
+    impl<T: ToString, const SEP: char> Distribution<Prefixed<T, SEP>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Prefixed<T, SEP> {
+            let mut s = SEP.to_string();
+            s.push_str(&rng.gen::<T>().to_string());
+
+            Prefixed(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([T, const SEP: char] Prefixed<T, SEP>, PhantomData);
+
+    /// Chooses between two string generators, and outputs the result of
+    /// sampling the chosen one.
+    ///
+    /// `A` is chosen with a probability of `PERCENT`%, which defaults to 50%,
+    /// and `B` is chosen otherwise. `PERCENT` must be no greater than 100,
+    /// which is checked at compile time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::company::CompanyName;
+    /// use faker_rand::en_us::names::FirstName;
+    /// use faker_rand::util::Either;
+    /// assert_eq!(
+    ///     "MacGyver, Dietrich, and McClure",
+    ///     rng.gen::<Either<FirstName, CompanyName>>().to_string()
+    /// );
+    /// assert_eq!("Reyes", rng.gen::<Either<FirstName, CompanyName>>().to_string());
+    ///
+    /// // With a PERCENT of 80, FirstName is chosen most of the time.
+    /// assert_eq!("Rachel", rng.gen::<Either<FirstName, CompanyName, 80>>().to_string());
+    /// assert_eq!("Sarah", rng.gen::<Either<FirstName, CompanyName, 80>>().to_string());
+    /// assert_eq!("John", rng.gen::<Either<FirstName, CompanyName, 80>>().to_string());
+    /// assert_eq!("Francisca", rng.gen::<Either<FirstName, CompanyName, 80>>().to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::company::CompanyName;
+    /// use faker_rand::en_us::names::FirstName;
+    /// use faker_rand::util::Either;
+    /// rng.gen::<Either<FirstName, CompanyName, 101>>();
+    /// ```
+    ///
+    /// To choose between more than two generators, you can nest `Either`. For
+    /// example, `Either<Either<A, B>, C>` chooses `C` half of the time, and `A`
+    /// or `B` a quarter of the time each.
+    pub struct Either<A, B, const PERCENT: u32 = 50>(String, PhantomData<(A, B)>);
+
+    impl<A, B, const PERCENT: u32> Either<A, B, PERCENT> {
+        const ASSERT_VALID: () = assert_percent(PERCENT);
+    }
+
+    impl<A: ToString, B: ToString, const PERCENT: u32> Distribution<Either<A, B, PERCENT>> for Standard
+    where
+        Standard: Distribution<A> + Distribution<B>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Either<A, B, PERCENT> {
+            let () = Either::<A, B, PERCENT>::ASSERT_VALID;
+
+            let s = if rng.gen_ratio(PERCENT, 100) {
+                rng.gen::<A>().to_string()
+            } else {
+                rng.gen::<B>().to_string()
+            };
+
+            Either(s, PhantomData)
+        }
+    }
+
+    __faker_impl_newtype!([A, B, const PERCENT: u32] Either<A, B, PERCENT>, PhantomData);
+
+    /// Chooses between two string generators with equal likelihood, and
+    /// outputs the result of sampling the chosen one.
+    ///
+    /// This is equivalent to [`Either`] with its default `PERCENT` of 50.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::en_us::names::{FirstName, LastName};
+    /// use faker_rand::util::OneOf2;
+    /// assert_eq!("Konopelski", rng.gen::<OneOf2<FirstName, LastName>>().to_string());
+    /// assert_eq!("Beier", rng.gen::<OneOf2<FirstName, LastName>>().to_string());
+    /// assert_eq!("Simonis", rng.gen::<OneOf2<FirstName, LastName>>().to_string());
+    /// assert_eq!("Schneider", rng.gen::<OneOf2<FirstName, LastName>>().to_string());
+    /// assert_eq!("Sarah", rng.gen::<OneOf2<FirstName, LastName>>().to_string());
+    /// ```
+    pub type OneOf2<A, B> = Either<A, B>;
+
+    /// A distribution that samples from a generator, but never returns the
+    /// same value twice.
+    ///
+    /// Unlike the other generators in this crate, `Unique` keeps track of the
+    /// values it has returned, and so it's used as a distribution value rather
+    /// than as a type parameter to [`rand::Rng::gen`]. Pass a reference to a
+    /// `Unique` to [`rand::Rng::sample`] instead:
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::util::{AsciiDigit, Unique};
+    /// let unique = Unique::<AsciiDigit>::new();
+    ///
+    /// let mut digits: Vec<String> = (0..10)
+    ///     .map(|_| rng.sample(&unique).to_string())
+    ///     .collect();
+    ///
+    /// digits.sort();
+    /// assert_eq!(vec!["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"], digits);
+    ///
+    /// // There are only ten ASCII digits, so there are no new values left.
+    /// assert_eq!(None, unique.try_sample(&mut rng));
+    /// ```
+    ///
+    /// `Unique` retries sampling from the underlying generator until it finds a
+    /// new value. If it doesn't find one within a maximum number of attempts
+    /// (1,000 by default, configurable with [`Unique::with_max_attempts`]),
+    /// [`Unique::try_sample`] returns [`None`], and sampling through the
+    /// [`Distribution`] implementation panics.
+    ///
+    /// The set of returned values is kept behind a [`Mutex`], so a `Unique` can
+    /// be shared between threads.
+    pub struct Unique<T> {
+        seen: Mutex<HashSet<String>>,
+        max_attempts: usize,
+        _generator: PhantomData<fn() -> T>,
+    }
+
+    impl<T> Unique<T> {
+        /// Creates a `Unique` that hasn't returned any values yet.
+        pub fn new() -> Self {
+            Self::with_max_attempts(1000)
+        }
+
+        /// Creates a `Unique` that makes at most `max_attempts` attempts to find
+        /// a new value each time it is sampled.
+        pub fn with_max_attempts(max_attempts: usize) -> Self {
+            Unique {
+                seen: Mutex::new(HashSet::new()),
+                max_attempts,
+                _generator: PhantomData,
+            }
+        }
+    }
+
+    impl<T: ToString> Unique<T>
+    where
+        Standard: Distribution<T>,
+    {
+        /// Samples a value that this `Unique` hasn't returned before, or returns
+        /// [`None`] if one couldn't be found within the maximum number of
+        /// attempts.
+        pub fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+            let mut seen = self.seen.lock().unwrap();
+            for _ in 0..self.max_attempts {
+                let value = rng.gen::<T>();
+                if seen.insert(value.to_string()) {
+                    return Some(value);
+                }
+            }
+
+            None
+        }
+    }
+
+    impl<T> Default for Unique<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T: ToString> Distribution<T> for Unique<T>
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+            match self.try_sample(rng) {
+                Some(value) => value,
+                None => panic!(
+                    "Unique<{}> could not find a new value in {} attempts; the underlying generator may have run out of unique values",
+                    std::any::type_name::<T>(),
+                    self.max_attempts
+                ),
+            }
+        }
+    }
+}
+
+/// Generators for "lorem ipsum" placeholder text.
+pub mod lorem {
+    use crate::util::{CapitalizeFirstLetter, Join, JoinRange, NumberInRange, TitleCase};
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    /// Generates a lorem ipsum word.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Word;
+    /// assert_eq!("impedit", rng.gen::<Word>().to_string());
+    /// ```
+    pub struct Word(&'static str);
+    faker_impl_from_file!(Word, "data/lorem_words");
+
+    /// Generates exactly `N` lorem ipsum words, separated by spaces.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Words;
+    /// assert_eq!("impedit", rng.gen::<Words<1>>().to_string());
+    /// assert_eq!("totam cumque debitis unde eum", rng.gen::<Words<5>>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<Words<5>>().to_string();
+    /// #     assert_eq!(5, s.split(' ').filter(|w| !w.is_empty()).count(), "{:?}", s);
+    /// #     assert_eq!(s.trim(), s);
+    /// # }
+    /// ```
+    pub struct Words<const N: usize>(String);
+
+    impl<const N: usize> Distribution<Words<N>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Words<N> {
+            Words(rng.gen::<Join<Word, N>>().into_inner())
+        }
+    }
+
+    __faker_impl_newtype!([const N: usize] Words<N>);
+
+    /// Generates between `MIN` and `MAX` (inclusive) lorem ipsum words,
+    /// separated by spaces.
+    ///
+    /// The number of words is chosen uniformly at random each time. `MIN` must
+    /// be no greater than `MAX`, which is checked at compile time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::WordsBetween;
+    /// assert_eq!("totam cumque debitis unde", rng.gen::<WordsBetween<2, 4>>().to_string());
+    /// assert_eq!("recusandae aut sequi aut", rng.gen::<WordsBetween<2, 4>>().to_string());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::WordsBetween;
+    /// rng.gen::<WordsBetween<4, 2>>();
+    /// ```
+    pub struct WordsBetween<const MIN: usize, const MAX: usize>(String);
+
+    impl<const MIN: usize, const MAX: usize> WordsBetween<MIN, MAX> {
+        const ASSERT_VALID: () = assert!(MIN <= MAX, "MIN must be no greater than MAX");
+    }
+
+    impl<const MIN: usize, const MAX: usize> Distribution<WordsBetween<MIN, MAX>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> WordsBetween<MIN, MAX> {
+            let () = WordsBetween::<MIN, MAX>::ASSERT_VALID;
+
+            WordsBetween(rng.gen::<JoinRange<Word, MIN, MAX>>().into_inner())
+        }
+    }
+
+    __faker_impl_newtype!([const MIN: usize, const MAX: usize] WordsBetween<MIN, MAX>);
+
+    /// Generates a lorem ipsum sentence of between `MIN` and `MAX` (inclusive)
+    /// words.
+    ///
+    /// The first word is capitalized, and the sentence ends with a period.
+    /// `MIN` and `MAX` default to 3 and 7. `MIN` must be at least 1, and no
+    /// greater than `MAX`.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Sentence;
+    /// assert_eq!(
+    ///     "Debitis recusandae aut sequi aut assumenda cupiditate.",
+    ///     rng.gen::<Sentence>().to_string()
+    /// );
+    /// assert_eq!("In doloribus expedita similique qui eos odit repellendus quae perspiciatis.", rng.gen::<Sentence<10, 20>>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<Sentence<10, 20>>().to_string();
+    /// #     assert!((10..=20).contains(&s.split(' ').count()), "{}", s);
+    /// #     assert!(s.starts_with(|c: char| c.is_uppercase()), "{}", s);
+    /// #     assert!(s.ends_with('.') && !s.ends_with(" ."), "{}", s);
+    /// #     let words: Vec<_> = s.to_lowercase().split(' ').map(String::from).collect();
+    /// #     assert!(words.windows(2).all(|w| w[0] != w[1]), "{}", s);
+    /// #     let s = rng.gen::<Sentence<1, 1>>().to_string();
+    /// #     assert!(!s.contains(' ') && s.ends_with('.'), "{}", s);
+    /// # }
+    /// ```
+    ///
+    /// Other values of `MIN` and `MAX` are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Sentence;
+    /// rng.gen::<Sentence<0, 3>>();
+    /// ```
+    pub struct Sentence<const MIN: usize = 3, const MAX: usize = 7>(String);
+
+    impl<const MIN: usize, const MAX: usize> Sentence<MIN, MAX> {
+        const ASSERT_VALID: () = assert_sentence_len(MIN, MAX);
+    }
+
+    impl<const MIN: usize, const MAX: usize> Distribution<Sentence<MIN, MAX>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Sentence<MIN, MAX> {
+            let () = Sentence::<MIN, MAX>::ASSERT_VALID;
+
+            // Sentence used to be made with faker_impl_from_templates, which
+            // chooses a template pattern even when there is only one. Doing
+            // the same keeps the output for a given RNG unchanged.
+            rng.gen_range(0..1u32);
+
+            let mut s = sentence_words::<Word, _>(rng, MIN, MAX);
+            s.push('.');
+            Sentence(s)
+        }
+    }
+
+    __faker_impl_newtype!([const MIN: usize, const MAX: usize] Sentence<MIN, MAX>);
+
+    /// Generates a lorem ipsum sentence of between `MIN` and `MAX` (inclusive)
+    /// words, which usually ends with a period, but sometimes ends with a
+    /// question mark or an exclamation mark.
+    ///
+    /// This is like [`Sentence`], except that only about 80% of sentences end
+    /// with ".". About 10% end with "?", and the other 10% with "!".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::SentenceVaried;
+    /// assert_eq!("Cumque debitis unde.", rng.gen::<SentenceVaried>().to_string());
+    /// assert_eq!("Ut sint corrupti est ab.", rng.gen::<SentenceVaried>().to_string());
+    /// assert_eq!("Doloribus similique qui eos odit repellendus.", rng.gen::<SentenceVaried>().to_string());
+    /// # let mut ends = std::collections::HashMap::new();
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<SentenceVaried<1, 5>>().to_string();
+    /// #     assert!((1..=5).contains(&s.split(' ').count()), "{}", s);
+    /// #     assert!(s.starts_with(|c: char| c.is_uppercase()), "{}", s);
+    /// #     *ends.entry(s.chars().last().unwrap()).or_insert(0) += 1;
+    /// # }
+    /// # assert_eq!(3, ends.len());
+    /// # assert!((7500..8500).contains(&ends[&'.']), "{:?}", ends);
+    /// # assert!((700..1300).contains(&ends[&'?']), "{:?}", ends);
+    /// # assert!((700..1300).contains(&ends[&'!']), "{:?}", ends);
+    /// ```
+    pub struct SentenceVaried<const MIN: usize = 3, const MAX: usize = 7>(String);
+
+    impl<const MIN: usize, const MAX: usize> SentenceVaried<MIN, MAX> {
+        const ASSERT_VALID: () = assert_sentence_len(MIN, MAX);
+    }
+
+    impl<const MIN: usize, const MAX: usize> Distribution<SentenceVaried<MIN, MAX>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SentenceVaried<MIN, MAX> {
+            let () = SentenceVaried::<MIN, MAX>::ASSERT_VALID;
+
+            let mut s = sentence_words::<Word, _>(rng, MIN, MAX);
+            s.push(match rng.gen_range(0..10) {
+                0 => '?',
+                1 => '!',
+                _ => '.',
+            });
+
+            SentenceVaried(s)
+        }
+    }
+
+    __faker_impl_newtype!([const MIN: usize, const MAX: usize] SentenceVaried<MIN, MAX>);
+
+    /// Checks the word counts of a sentence type, at compile time when used to
+    /// define an associated constant.
+    const fn assert_sentence_len(min: usize, max: usize) {
+        assert!(min > 0, "sentences must have at least one word");
+        assert!(
+            min <= max,
+            "sentences must have a non-empty range of word counts"
+        );
+    }
+
+    /// Generates between `min` and `max` words, the first of them capitalized,
+    /// without any closing punctuation.
+    ///
+    /// A word is redrawn if it's the same as the word before it, up to
+    /// `MAX_WORD_ATTEMPTS` times in all, after which the duplicate is kept.
+    fn sentence_words<W, R>(rng: &mut R, min: usize, max: usize) -> String
+    where
+        W: ToString,
+        Standard: Distribution<W>,
+        R: Rng + ?Sized,
+    {
+        const MAX_WORD_ATTEMPTS: usize = 5;
+
+        // The first word used to be made with faker_impl_from_templates, which
+        // chooses a template pattern even when there is only one. Doing the
+        // same keeps the output for a given RNG unchanged.
+        rng.gen_range(0..1u32);
+
+        let mut s = rng.gen::<CapitalizeFirstLetter<W>>().to_string();
+        let mut prev = s.to_lowercase();
+        for _ in 1..rng.gen_range(min..=max) {
+            let mut word = rng.gen::<W>().to_string();
+            for _ in 1..MAX_WORD_ATTEMPTS {
+                if word != prev {
+                    break;
+                }
+
+                word = rng.gen::<W>().to_string();
+            }
+
+            s.push(' ');
+            s.push_str(&word);
+            prev = word;
+        }
+
+        s
+    }
+
+    /// Generates a lorem ipsum title of between 2 and 6 words, such as
+    /// "Impedit Totam Cumque".
+    ///
+    /// Every word is capitalized, words are separated by single spaces, and
+    /// there is no punctuation.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Title;
+    /// assert_eq!("Debitis Unde Eum Recusandae Aut", rng.gen::<Title>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<Title>().to_string();
+    /// #     let words: Vec<_> = s.split(' ').collect();
+    /// #     assert!((2..=6).contains(&words.len()), "{}", s);
+    /// #     assert!(words.iter().all(|w| w.starts_with(char::is_uppercase)), "{}", s);
+    /// #     assert!(s.chars().all(|c| c.is_alphabetic() || c == ' '), "{}", s);
+    /// # }
+    /// ```
+    ///
+    /// `Title` can be used in templates, for instance to make a blog post:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::lorem::{Paragraph, Title};
+    ///
+    /// struct BlogPost(String);
+    /// faker_impl_from_templates! {
+    ///     BlogPost;
+    ///
+    ///     "# {}\n\n{}\n", Title, Paragraph;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!(
+    ///     "# Unde Eum Recusandae Aut Sequi Aut\n\nEt necessitatibus fugiat voluptas facere repudiandae. Nisi illo itaque nesciunt sunt. Consequatur corrupti eum cupiditate exercitationem.\n",
+    ///     rng.gen::<BlogPost>().to_string()
+    /// );
+    /// ```
+    pub struct Title(String);
+    faker_impl_from_templates! {
+        Title;
+
+        "{}", TitleCase<WordsBetween<2, 6>>;
+    }
+
+    /// Generates a URL slug of between 2 and 5 lorem ipsum words joined by
+    /// hyphens, such as "cumque-debitis-unde".
+    ///
+    /// The output always matches the regular expression `[a-z]+(-[a-z]+)*`.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Slug;
+    /// assert_eq!("debitis-unde", rng.gen::<Slug>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<Slug>().to_string();
+    /// #     let words: Vec<_> = s.split('-').collect();
+    /// #     assert!((2..=5).contains(&words.len()), "{}", s);
+    /// #     assert!(words.iter().all(|w| !w.is_empty() && w.bytes().all(|b| b.is_ascii_lowercase())), "{}", s);
+    /// # }
+    /// ```
+    pub struct Slug(String);
+    faker_impl_from_templates! {
+        Slug;
+
+        "{}", JoinRange<Word, 2, 5, '-'>;
+    }
+
+    /// Generates a [`Slug`] followed by a hyphen and a number from 1 to 999,
+    /// such as "cumque-debitis-unde-42".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::NumberedSlug;
+    /// assert_eq!("eum-recusandae-aut-sequi-880", rng.gen::<NumberedSlug>().to_string());
+    /// ```
+    pub struct NumberedSlug(String);
+    faker_impl_from_templates! {
+        NumberedSlug;
+
+        "{}-{}", Slug, NumberInRange<1, 999>;
+    }
+
+    /// Generates a hashtag of between 1 and 3 capitalized lorem ipsum words,
+    /// such as "#CumqueDebitis".
+    ///
+    /// The output never contains spaces.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Hashtag;
+    /// assert_eq!("#DebitisUndeEum", rng.gen::<Hashtag>().to_string());
+    /// assert_eq!("#DoloresSintCorrupti", rng.gen::<Hashtag>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<Hashtag>().to_string();
+    /// #     let words = s.strip_prefix('#').unwrap();
+    /// #     assert!(words.bytes().all(|b| b.is_ascii_alphabetic()), "{}", s);
+    /// #     assert!((1..=3).contains(&words.matches(|c: char| c.is_uppercase()).count()), "{}", s);
+    /// # }
+    /// ```
+    pub struct Hashtag(String);
+    faker_impl_from_templates! {
+        Hashtag;
+
+        "#{}", repeat(1..=3, "", CapitalizeFirstLetter<Word>);
+    }
+
+    /// Generates lorem ipsum text of at most `N` characters.
+    ///
+    /// The text is made of [`Sentence`]s, separated by single spaces, and is
+    /// cut off at the last space that keeps it within `N` characters. So the
+    /// text is usually a little shorter than `N`, and may end partway through
+    /// a sentence, but never ends with a space. If `N` is shorter than the
+    /// first word, the text is that word cut off at `N` characters. Lengths are
+    /// counted in [`char`]s, not bytes.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Text;
+    /// assert_eq!("Debitis recusandae aut sequi aut assumenda cupiditate. In doloribus expedita.", rng.gen::<Text<80>>().to_string());
+    /// assert_eq!("Aut", rng.gen::<Text<3>>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     for s in [
+    /// #         rng.gen::<Text<0>>().to_string(),
+    /// #         rng.gen::<Text<1>>().to_string(),
+    /// #         rng.gen::<Text<10>>().to_string(),
+    /// #         rng.gen::<Text<200>>().to_string(),
+    /// #     ] {
+    /// #         assert!(!s.starts_with(' ') && !s.ends_with(' '), "{:?}", s);
+    /// #         assert!(!s.contains("  "), "{:?}", s);
+    /// #     }
+    /// #     assert_eq!("", rng.gen::<Text<0>>().to_string());
+    /// #     assert_eq!(1, rng.gen::<Text<1>>().to_string().chars().count());
+    /// #     assert!(rng.gen::<Text<10>>().to_string().chars().count() <= 10);
+    /// #     assert!((150..=200).contains(&rng.gen::<Text<200>>().to_string().chars().count()));
+    /// # }
+    /// ```
+    pub struct Text<const N: usize>(String);
+
+    impl<const N: usize> Distribution<Text<N>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Text<N> {
+            let mut s = String::new();
+            let mut chars = 0;
+            while chars < N {
+                if !s.is_empty() {
+                    s.push(' ');
+                    chars += 1;
+                }
+
+                let sentence = rng.gen::<Sentence>();
+                s.push_str(&sentence);
+                chars += sentence.chars().count();
+            }
+
+            if chars > N {
+                // The byte offset of the first character past the limit.
+                let limit = s.char_indices().nth(N).map_or(s.len(), |(i, _)| i);
+                let end = if s[limit..].starts_with(' ') {
+                    limit
+                } else {
+                    s[..limit].rfind(' ').unwrap_or(limit)
+                };
+
+                s.truncate(end);
+            }
+
+            Text(s)
+        }
+    }
+
+    __faker_impl_newtype!([const N: usize] Text<N>);
+
+    /// Generates a lorem ipsum paragraph.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Paragraph;
+    /// assert_eq!(
+    ///     "Culpa dolorem dolores ut dolores. Voluptas consectetur et animi necessitatibus fugiat. Perspiciatis error distinctio velit voluptate dolorem. Autem et iusto. Iusto eum cupiditate exercitationem voluptates.",
+    ///     rng.gen::<Paragraph>().to_string()
+    /// );
+    /// ```
+    pub struct Paragraph(String);
+    faker_impl_from_templates! {
+        Paragraph;
+
+        "{}", repeat(3..=5, " ", Sentence);
+    }
+
+    /// Generates multiple lorem ipsum paragraphs.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::Paragraphs;
+    /// assert_eq!(
+    ///     "Ut sint corrupti est ab. Et necessitatibus fugiat voluptas facere repudiandae. Nisi illo itaque nesciunt sunt. Consequatur corrupti eum cupiditate exercitationem. Est sit iusto magni.\nDoloribus in cum ad reprehenderit. Numquam error illum quae animi voluptatem quo. Consequatur corrupti cupiditate. Et natus distinctio ea consequatur ipsam sapiente.\nSed eum officiis ut et. Voluptatem consequatur fuga explicabo asperiores. Unde est vel quia quo aliquid. Delectus sequi unde voluptas. Quo possimus aut optio et.\nDicta eos amet rerum. Eveniet repudiandae aspernatur sed. Enim sed ea dignissimos impedit nam modi.\nQuidem temporibus necessitatibus expedita est ratione. Recusandae et sunt qui suscipit et est. Doloremque aliquam beatae laborum. Blanditiis earum repellat accusamus eum.\n",
+    ///     rng.gen::<Paragraphs>().to_string()
+    /// );
+    /// ```
+    pub struct Paragraphs(String);
+    faker_impl_from_templates! {
+        Paragraphs;
+
+        "{}\n", repeat(3..=5, "\n", Paragraph);
+    }
+
+    /// Generates a Markdown bulleted list of between 3 and 5 lorem ipsum
+    /// sentences.
+    ///
+    /// Each item is on its own line, starts with `- `, and ends with a newline.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::BulletList;
+    /// assert_eq!(
+    ///     "- Dolorem ut dolores sint corrupti.\n- Doloribus similique qui eos odit repellendus.\n- Nisi illo itaque nesciunt sunt.\n- Iusto eum cupiditate exercitationem voluptates.\n- Sit vitae repellat quisquam est.\n",
+    ///     rng.gen::<BulletList>().to_string()
+    /// );
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<BulletList>().to_string();
+    /// #     assert!(s.ends_with('\n'), "{:?}", s);
+    /// #     assert!((3..=5).contains(&s.lines().count()), "{:?}", s);
+    /// #     assert!(s.lines().all(|line| line.starts_with("- ")), "{:?}", s);
+    /// # }
+    /// ```
+    pub struct BulletList(String);
+    faker_impl_from_templates! {
+        BulletList;
+
+        "{}\n", repeat(3..=5, "\n", BulletListItem);
+    }
+
+    struct BulletListItem(String);
+    faker_impl_from_templates! {
+        BulletListItem;
+
+        "- {}", Sentence;
+    }
+
+    /// Generates a lorem ipsum Markdown document.
+    ///
+    /// The document is made of a level-one heading, a paragraph, a
+    /// [`BulletList`], a paragraph that starts with an inline code span, and a
+    /// one-line block quote. Blocks are separated by blank lines, so the output
+    /// is valid CommonMark.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::MarkdownDocument;
+    /// assert_eq!(
+    ///     "# Unde Eum Recusandae Aut Sequi Aut\n\nEt necessitatibus fugiat voluptas facere repudiandae. Nisi illo itaque nesciunt sunt. Consequatur corrupti eum cupiditate exercitationem.\n\n- Iusto odio quia nam sequi doloribus.\n- Commodi fugit sit et excepturi consequatur ex.\n- Atque et corrupti autem nihil iure.\n- Voluptas veritatis magnam sapiente molestiae sed.\n\nRerum `vel` sint ab. Ut consequatur fuga explicabo asperiores. Unde est vel quia quo aliquid. Delectus sequi unde voluptas. Quo possimus aut optio et.\n\n> Soluta dicta eos.\n",
+    ///     rng.gen::<MarkdownDocument>().to_string()
+    /// );
+    /// # for _ in 0..100 {
+    /// #     let s = rng.gen::<MarkdownDocument>().to_string();
+    /// #     let blocks: Vec<_> = s.trim_end().split("\n\n").collect();
+    /// #     assert_eq!(5, blocks.len(), "{:?}", s);
+    /// #     assert!(blocks[0].starts_with("# "), "{:?}", s);
+    /// #     assert!(blocks[2].lines().all(|line| line.starts_with("- ")), "{:?}", s);
+    /// #     assert_eq!(2, blocks[3].matches('`').count(), "{:?}", s);
+    /// #     assert!(blocks[4].starts_with("> "), "{:?}", s);
+    /// # }
+    /// ```
+    pub struct MarkdownDocument(String);
+    faker_impl_from_templates! {
+        MarkdownDocument;
+
+        "# {}\n\n{}\n\n{}\n{} {}\n\n> {}\n", Title, Paragraph, BulletList, CodeSpanSentence, Paragraph, Sentence;
+    }
+
+    struct CodeSpanSentence(String);
+    faker_impl_from_templates! {
+        CodeSpanSentence;
+
+        "{} `{}` {}.", CapitalizeFirstLetter<WordsBetween<1, 3>>, Word, WordsBetween<1, 3>;
+    }
+
+    /// Defines `Word`, `Sentence`, and `Paragraph` generators for filler text
+    /// made from the words in `$file`, instead of lorem ipsum.
+    ///
+    /// `$kind` describes the words, and `$example` is one of them, for use in doc
+    /// comments.
+    #[cfg(any(feature = "hipster", feature = "corporate"))]
+    macro_rules! lorem_corpus {
+        ($kind: literal, $example: literal, $file: literal) => {
+            use rand::distributions::{Distribution, Standard};
+            use rand::Rng;
+
+            #[doc = concat!("Generates a ", $kind, " word or phrase, such as \"", $example, "\".")]
+            pub struct Word(&'static str);
+            faker_impl_from_file!(Word, $file);
+
+            #[doc = concat!("Generates a ", $kind, " sentence of between 3 and 7 [`Word`]s.")]
+            ///
+            /// The first word is capitalized, and the sentence ends with a period.
+            /// As in [`lorem::Sentence`](super::Sentence), the same word rarely
+            /// appears twice in a row.
+            pub struct Sentence(String);
+
+            impl Distribution<Sentence> for Standard {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Sentence {
+                    let mut s = super::sentence_words::<Word, _>(rng, 3, 7);
+                    s.push('.');
+                    Sentence(s)
+                }
+            }
+
+            __faker_impl_newtype!([] Sentence);
+
+            #[doc = concat!("Generates a ", $kind, " paragraph of between 3 and 5 [`Sentence`]s.")]
+            pub struct Paragraph(String);
+            faker_impl_from_templates! {
+                Paragraph;
+
+                "{}", repeat(3..=5, " ", Sentence);
+            }
+        };
+    }
+
+    /// Generators for hipster filler text, in place of lorem ipsum.
+    ///
+    /// This module is only available if this crate's `hipster` feature is enabled.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::hipster::{Paragraph, Sentence, Word};
+    /// assert_eq!("slow-carb", rng.gen::<Word>().to_string());
+    /// assert_eq!("Skateboard unicorn poke.", rng.gen::<Sentence>().to_string());
+    /// assert_eq!(
+    ///     "Vegan mumblecore retro biodiesel quinoa. Yuccie pour-over ugh cardigan bicycle polaroid. Letterpress kombucha taxidermy brooklyn blog master single-origin. Master paleo gentrify vexillologist woke. Brunch seitan snackwave vexillologist cardigan flannel.",
+    ///     rng.gen::<Paragraph>().to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "hipster")]
+    pub mod hipster {
+        lorem_corpus!("hipster", "kombucha", "data/lorem/hipster_words");
+    }
+
+    /// Generators for corporate jargon filler text, in place of lorem ipsum.
+    ///
+    /// This module is only available if this crate's `corporate` feature is enabled.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::corporate::{Paragraph, Sentence, Word};
+    /// assert_eq!("pivot", rng.gen::<Word>().to_string());
+    /// assert_eq!("Benchmark streamline vertical seamless circle back thought leadership robust.", rng.gen::<Sentence>().to_string());
+    /// assert_eq!(
+    ///     "World-class monetize move the needle scalable bandwidth mindshare. Ideate holistic best practices incentivize paradigm thought leadership holistic. Growth hacking transform win-win onboarding disrupt.",
+    ///     rng.gen::<Paragraph>().to_string()
+    /// );
+    /// ```
+    #[cfg(feature = "corporate")]
+    pub mod corporate {
+        lorem_corpus!("corporate jargon", "synergy", "data/lorem/corporate_words");
+    }
+
+    /// Generates between 2 and 5 lorem ipsum paragraphs as HTML, each wrapped
+    /// in a `<p>` tag.
+    ///
+    /// Paragraphs are separated by newlines. About one in five sentences has
+    /// one of its words wrapped in a `<strong>` or `<em>` tag. The output is
+    /// always well-formed, and never needs escaping.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::lorem::HtmlParagraphs;
+    /// assert_eq!(
+    ///     "<p>Ut sint corrupti est ab. Et necessitatibus fugiat voluptas facere repudiandae. Nisi illo itaque nesciunt sunt. Corrupti exercitationem voluptates sapiente magnam.</p>\n<p>Repellat <em>est</em> minus repudiandae qui rerum. Commodi fugit sit et excepturi consequatur ex. Atque et corrupti autem nihil iure. Voluptas veritatis magnam sapiente molestiae sed.</p>",
+    ///     rng.gen::<HtmlParagraphs>().to_string()
+    /// );
+    /// # let (mut strong, mut em) = (false, false);
+    /// # for _ in 0..1000 {
+    /// #     let s = rng.gen::<HtmlParagraphs>().to_string();
+    /// #     strong |= s.contains("<strong>");
+    /// #     em |= s.contains("<em>");
+    /// #     assert!(!s.contains('&'), "{}", s);
+    /// #     assert!((2..=5).contains(&s.lines().count()), "{}", s);
+    /// #     for line in s.lines() {
+    /// #         assert!(line.starts_with("<p>") && line.ends_with("</p>"), "{}", s);
+    /// #         let text = ["<p>", "</p>", "<strong>", "</strong>", "<em>", "</em>"]
+    /// #             .iter()
+    /// #             .fold(line.to_string(), |text, tag| text.replace(tag, ""));
+    /// #         for sentence in text.split_terminator('.') {
+    /// #             assert!((3..=7).contains(&sentence.trim().split(' ').count()), "{}", s);
+    /// #         }
+    /// #     }
+    /// #
+    /// #     let mut open = Vec::new();
+    /// #     let mut rest = s.as_str();
+    /// #     while let Some(start) = rest.find('<') {
+    /// #         let end = start + rest[start..].find('>').unwrap();
+    /// #         let tag = &rest[start + 1..end];
+    /// #         match tag.strip_prefix('/') {
+    /// #             Some(tag) => assert_eq!(Some(tag), open.pop(), "{}", s),
+    /// #             None => {
+    /// #                 assert!(["p", "strong", "em"].contains(&tag), "{}", s);
+    /// #                 open.push(tag);
+    /// #             }
+    /// #         }
+    /// #         rest = &rest[end + 1..];
+    /// #     }
+    /// #     assert!(open.is_empty(), "{}", s);
+    /// #     assert!(!rest.contains('>'), "{}", s);
+    /// # }
+    /// # assert!(strong && em);
+    /// ```
+    pub struct HtmlParagraphs(String);
+    faker_impl_from_templates! {
+        HtmlParagraphs;
+
+        "{}", repeat(2..=5, "\n", HtmlParagraph);
+    }
+
+    struct HtmlParagraph(String);
+    faker_impl_from_templates! {
+        HtmlParagraph;
+
+        "<p>{}</p>", repeat(3..=5, " ", HtmlSentence);
+    }
+
+    /// A [`Sentence`] which, one time in five, has one of its words wrapped in
+    /// an emphasis tag.
+    struct HtmlSentence(String);
+
+    impl Distribution<HtmlSentence> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HtmlSentence {
+            let sentence = rng.gen::<Sentence>().0;
+            if !rng.gen_ratio(1, 5) {
+                return HtmlSentence(sentence);
+            }
+
+            // Leave the closing period outside of the tag.
+            let mut words: Vec<_> = sentence.trim_end_matches('.').split(' ').collect();
+            let emphasized = rng.gen_range(0..words.len());
+            let tag = rng.gen::<HtmlEmphasisTag>();
+            let wrapped = format!("<{tag}>{}</{tag}>", words[emphasized]);
+            words[emphasized] = &wrapped;
+
+            HtmlSentence(format!("{}.", words.join(" ")))
+        }
+    }
+
+    __faker_impl_newtype!([] HtmlSentence);
+
+    struct HtmlEmphasisTag(String);
+    faker_impl_from_templates! {
+        HtmlEmphasisTag;
+
+        "strong";
+        "em";
+    }
+}
+
+/// Localized generators for English as spoken in the United States (`en-US`).
+pub mod en_us {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    pub mod names {
+        /// Generates a first name.
+        ///
+        /// Common first names, such as "Mary" or "John", are generated more
+        /// often than uncommon ones.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::FirstName;
+        /// assert_eq!("Michael", rng.gen::<FirstName>().to_string());
+        /// # let mut weights = std::collections::HashMap::new();
+        /// # for line in include_str!("data/en_us/first_names").lines() {
+        /// #     match line.split_once('\t') {
+        /// #         Some((name, weight)) => weights.insert(name, weight.parse::<u32>().unwrap()),
+        /// #         None => weights.insert(line, 1),
+        /// #     };
+        /// # }
+        /// # let total_weight: u32 = weights.values().sum();
+        /// #
+        /// # // Group the names by weight, and check that each group is generated
+        /// # // about as often as its share of the total weight.
+        /// # let samples = 200_000;
+        /// # let mut counts = std::collections::BTreeMap::new();
+        /// # for _ in 0..samples {
+        /// #     *counts.entry(weights[&*rng.gen::<FirstName>()]).or_insert(0) += 1;
+        /// # }
+        /// # assert_eq!(vec![&1, &10, &20, &40], counts.keys().collect::<Vec<_>>());
+        /// # for (weight, count) in counts {
+        /// #     let group_weight: u32 = weights.values().filter(|&&w| w == weight).sum();
+        /// #     let expected = samples as f64 * group_weight as f64 / total_weight as f64;
+        /// #     assert!((count as f64 - expected).abs() < expected * 0.05, "{} {} {}", weight, count, expected);
+        /// # }
+        /// ```
+        ///
+        /// If this crate's `serde` feature is enabled, a `FirstName` serializes
+        /// as a plain string:
+        ///
+        /// ```
+        /// # #[cfg(feature = "serde")]
+        /// # {
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::FirstName;
+        /// let name: FirstName = rng.gen();
+        /// assert_eq!(r#""Michael""#, serde_json::to_string(&name).unwrap());
+        /// # }
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/en_us/first_names");
+
+        /// Generates a last name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::LastName;
+        /// assert_eq!("Quitzon", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/en_us/last_names");
+
+        /// Generates a name prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::NamePrefix;
+        /// assert_eq!("Miss", rng.gen::<NamePrefix>().to_string());
+        /// ```
+        pub struct NamePrefix(&'static str);
+        faker_impl_from_file!(NamePrefix, "data/en_us/name_prefixes");
+
+        /// Generates a name suffix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::NameSuffix;
+        /// assert_eq!("IV", rng.gen::<NameSuffix>().to_string());
+        /// ```
+        pub struct NameSuffix(&'static str);
+        faker_impl_from_file!(NameSuffix, "data/en_us/name_suffixes");
+
+        /// Generates a full name, including possibly a prefix, suffix, or both.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::names::FullName;
+        /// assert_eq!("Sarah Simonis DVM", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            "{} {}", FirstName, LastName;
+            "{} {} {}", NamePrefix, FirstName, LastName;
+            "{} {} {}", FirstName, LastName, NameSuffix;
+            "{} {} {} {}", NamePrefix, FirstName, LastName, NameSuffix;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postal codes, etc.).
+    pub mod addresses {
+        use super::names::{FirstName, FullName, LastName};
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit};
+        use rand::distributions::{Distribution, Standard};
+        use rand::Rng;
+        use std::sync::OnceLock;
+
+        struct CityPrefix(&'static str);
+        faker_impl_from_file!(CityPrefix, "data/en_us/city_prefixes");
+
+        struct CitySuffix(&'static str);
+        faker_impl_from_file!(CitySuffix, "data/en_us/city_suffixes");
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::CityName;
+        /// assert_eq!("Sarahmouth", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(String);
+        faker_impl_from_templates! {
+            CityName;
+
+            "{} {}{}", CityPrefix, FirstName, CitySuffix;
+            "{} {}", CityPrefix, FirstName;
+            "{}{}", FirstName, CitySuffix;
+            "{}{}", LastName, CitySuffix;
+        }
+
+        struct StreetSuffix(&'static str);
+        faker_impl_from_file!(StreetSuffix, "data/en_us/street_suffixes");
+
+        /// Generates a street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::StreetName;
+        /// assert_eq!("Renner Mission", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(String);
+        faker_impl_from_templates! {
+            StreetName;
+
+            "{} {}", FirstName, StreetSuffix;
+            "{} {}", LastName, StreetSuffix;
+        }
+
+        struct BuildingNumber(String);
+        faker_impl_from_templates! {
+            BuildingNumber;
+
+            "{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit;
+            "{}{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+            "{}{}{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a street address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::StreetAddress;
+        /// assert_eq!("6489 Sarah Springs", rng.gen::<StreetAddress>().to_string());
+        /// # for _ in 0..1000 {
+        /// #     assert!(!rng.gen::<StreetAddress>().to_string().starts_with('0'));
+        /// # }
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{} {}", BuildingNumber, StreetName;
+        }
+
+        /// Generates a secondary address (e.g. an apartment number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::SecondaryAddress;
+        /// assert_eq!("Suite 755", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "Apt. {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
+            "Suite {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a first-level administrative division (e.g. one of the 50
+        /// states).
+        ///
+        /// Currently, other top-level divisions in USA, such as the District of
+        /// Columbia or the unincorporated organized territories (e.g. Puerto
+        /// Rico), are not included in this list. This may be changed in a
+        /// future minor version of this crate.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::Division;
+        /// assert_eq!("Oklahoma", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/en_us/divisions");
+
+        /// Generates an abbreviated first-level division (e.g. the two-letter
+        /// abbreviation for one of the 50 states).
+        ///
+        /// See note in [`Division`] on the inclusion of entities other than the
+        /// 50 states, and how this may change in a minor version of this crate.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::DivisionAbbreviation;
+        /// assert_eq!("OK", rng.gen::<DivisionAbbreviation>().to_string());
+        /// ```
+        pub struct DivisionAbbreviation(&'static str);
+        faker_impl_from_file!(DivisionAbbreviation, "data/en_us/division_abbreviations");
+
+        /// Generates a postal code (a.k.a. a ZIP Code).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::PostalCode;
+        /// assert_eq!("75548-9960", rng.gen::<PostalCode>().to_string());
+        /// ```
+        pub struct PostalCode(String);
+        faker_impl_from_templates! {
+            PostalCode;
+
+            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+            "{}{}{}{}{}-{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Each division abbreviation, along with every three-digit prefix of the
+        /// ZIP Codes in that division.
+        fn zip_prefixes() -> &'static [(&'static str, Vec<u16>)] {
+            static ZIP_PREFIXES: OnceLock<Vec<(&'static str, Vec<u16>)>> = OnceLock::new();
+
+            ZIP_PREFIXES.get_or_init(|| {
+                include_str!("data/en_us/zip_prefixes")
+                    .lines()
+                    .map(|line| {
+                        let (division, ranges) = line.split_once('\t').unwrap();
+                        let prefixes = ranges
+                            .split(' ')
+                            .flat_map(|range| {
+                                let (start, end) = range.split_once('-').unwrap_or((range, range));
+                                start.parse().unwrap()..=end.parse().unwrap()
+                            })
+                            .collect();
+
+                        (division, prefixes)
+                    })
+                    .collect()
+            })
+        }
+
+        /// Generates an abbreviated first-level division followed by a postal
+        /// code in that division, such as "MA 02134".
+        ///
+        /// The divisions are the same as those of [`DivisionAbbreviation`], but
+        /// unlike combining [`DivisionAbbreviation`] with [`PostalCode`], the
+        /// first three digits of the postal code are always among those that
+        /// the division actually uses.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::DivisionAndPostalCode;
+        /// assert_eq!("OK 73950-1953", rng.gen::<DivisionAndPostalCode>().to_string());
+        /// assert_eq!("NJ 07882-4965", rng.gen::<DivisionAndPostalCode>().to_string());
+        /// # let mut seen = std::collections::HashSet::new();
+        /// # for _ in 0..10000 {
+        /// #     let s = rng.gen::<DivisionAndPostalCode>().to_string();
+        /// #     let (division, zip) = s.split_once(' ').unwrap();
+        /// #     assert!(zip.len() == 5 || (zip.len() == 10 && &zip[5..6] == "-"), "{}", s);
+        /// #     let prefix: u16 = zip[..3].parse().unwrap();
+        /// #     let ok = match division {
+        /// #         "AL" => (350..=369).contains(&prefix),
+        /// #         "AK" => (995..=999).contains(&prefix),
+        /// #         "AZ" => (850..=865).contains(&prefix),
+        /// #         "AR" => (716..=729).contains(&prefix),
+        /// #         "CA" => (900..=961).contains(&prefix),
+        /// #         "CO" => (800..=816).contains(&prefix),
+        /// #         "CT" => (60..=69).contains(&prefix),
+        /// #         "DE" => (197..=199).contains(&prefix),
+        /// #         "FL" => (320..=349).contains(&prefix),
+        /// #         "GA" => (300..=319).contains(&prefix) || (398..=399).contains(&prefix),
+        /// #         "HI" => (967..=968).contains(&prefix),
+        /// #         "ID" => (832..=838).contains(&prefix),
+        /// #         "IL" => (600..=629).contains(&prefix),
+        /// #         "IN" => (460..=479).contains(&prefix),
+        /// #         "IA" => (500..=528).contains(&prefix),
+        /// #         "KS" => (660..=679).contains(&prefix),
+        /// #         "KY" => (400..=427).contains(&prefix),
+        /// #         "LA" => (700..=714).contains(&prefix),
+        /// #         "ME" => (39..=49).contains(&prefix),
+        /// #         "MD" => (206..=219).contains(&prefix),
+        /// #         "MA" => (10..=27).contains(&prefix) || prefix == 55,
+        /// #         "MI" => (480..=499).contains(&prefix),
+        /// #         "MN" => (550..=567).contains(&prefix),
+        /// #         "MS" => (386..=397).contains(&prefix),
+        /// #         "MO" => (630..=658).contains(&prefix),
+        /// #         "MT" => (590..=599).contains(&prefix),
+        /// #         "NE" => (680..=693).contains(&prefix),
+        /// #         "NV" => (889..=898).contains(&prefix),
+        /// #         "NH" => (30..=38).contains(&prefix),
+        /// #         "NJ" => (70..=89).contains(&prefix),
+        /// #         "NM" => (870..=884).contains(&prefix),
+        /// #         "NY" => prefix == 5 || (100..=149).contains(&prefix),
+        /// #         "NC" => (270..=289).contains(&prefix),
+        /// #         "ND" => (580..=588).contains(&prefix),
+        /// #         "OH" => (430..=459).contains(&prefix),
+        /// #         "OK" => (730..=749).contains(&prefix),
+        /// #         "OR" => (970..=979).contains(&prefix),
+        /// #         "PA" => (150..=196).contains(&prefix),
+        /// #         "RI" => (28..=29).contains(&prefix),
+        /// #         "SC" => (290..=299).contains(&prefix),
+        /// #         "SD" => (570..=577).contains(&prefix),
+        /// #         "TN" => (370..=385).contains(&prefix),
+        /// #         "TX" => prefix == 733 || (750..=799).contains(&prefix) || prefix == 885,
+        /// #         "UT" => (840..=847).contains(&prefix),
+        /// #         "VT" => (50..=59).contains(&prefix) && prefix != 55,
+        /// #         "VA" => prefix == 201 || (220..=246).contains(&prefix),
+        /// #         "WA" => (980..=994).contains(&prefix),
+        /// #         "WV" => (247..=268).contains(&prefix),
+        /// #         "WI" => (530..=549).contains(&prefix),
+        /// #         "WY" => (820..=831).contains(&prefix),
+        /// #         _ => panic!("unexpected division: {}", s),
+        /// #     };
+        /// #     assert!(ok, "{}", s);
+        /// #     seen.insert(division.to_owned());
+        /// # }
+        /// # assert_eq!(50, seen.len());
+        /// ```
+        pub struct DivisionAndPostalCode(String);
+
+        impl Distribution<DivisionAndPostalCode> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DivisionAndPostalCode {
+                let zip_prefixes = zip_prefixes();
+                let (division, prefixes) = &zip_prefixes[rng.gen_range(0..zip_prefixes.len())];
+                let prefix = prefixes[rng.gen_range(0..prefixes.len())];
+                let suffix = rng.gen_range(0..100);
+
+                let mut s = format!("{} {:03}{:02}", division, prefix, suffix);
+                if rng.gen() {
+                    s.push_str(&format!("-{:04}", rng.gen_range(0..10000)));
+                }
+
+                DivisionAndPostalCode(s)
+            }
+        }
+
+        __faker_impl_newtype!([] DivisionAndPostalCode);
+
+        /// Generates a full postal address.
+        ///
+        /// The postal code is always one that's used in the address's division.
+        /// See [`DivisionAndPostalCode`].
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::addresses::Address;
+        /// assert_eq!(
+        ///     "Sarah Simonis DVM\n25364 Marks Passage Apt. 057\nMarvinborough, MA 01179-0657\n",
+        ///     rng.gen::<Address>().to_string()
+        /// );
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "{}\n{}\n{}, {}\n", FullName, StreetAddress, CityName, DivisionAndPostalCode;
+            "{}\n{} {}\n{}, {}\n", FullName, StreetAddress, SecondaryAddress, CityName, DivisionAndPostalCode;
+        }
+    }
+
+    /// Generators for company names and slogans.
+    pub mod company {
+        use super::names::{FirstName, LastName};
+        use crate::util::AsciiDigit;
+
+        struct CompanySuffix(&'static str);
+        faker_impl_from_file!(CompanySuffix, "data/en_us/company_suffixes");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::company::CompanyName;
+        /// assert_eq!("Konopelski, Price, and Beier", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "{} {}", FirstName, CompanySuffix;
+            "{}-{}", LastName, LastName;
+            "{}, {}, and {}", LastName, LastName, LastName;
+        }
+
+        struct SloganAdjective(&'static str);
+        faker_impl_from_file!(SloganAdjective, "data/en_us/slogan_adjectives");
+
+        struct SloganDescriptor(&'static str);
+        faker_impl_from_file!(SloganDescriptor, "data/en_us/slogan_descriptors");
+
+        struct SloganNouns(&'static str);
+        faker_impl_from_file!(SloganNouns, "data/en_us/slogan_nouns");
+
+        /// Generates a company slogan.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::company::Slogan;
+        /// assert_eq!("Business-focused intermediate applications", rng.gen::<Slogan>().to_string());
+        /// ```
+        pub struct Slogan(String);
+        faker_impl_from_templates! {
+            Slogan;
+
+            "{} {} {}", SloganAdjective, SloganDescriptor, SloganNouns;
+        }
+
+        /// Generates a job title, such as "Senior Markets Analyst".
+        ///
+        /// This is the same generator as [`jobs::JobTitle`](super::jobs::JobTitle),
+        /// which is where the rest of the job-related generators are.
+        pub use super::jobs::JobTitle;
+
+        struct EinPrefix(&'static str);
+        faker_impl_from_file!(EinPrefix, "data/en_us/ein_prefixes");
+
+        /// Generates an Employer Identification Number, such as "12-3456789".
+        ///
+        /// The first two digits are always a prefix that the IRS assigns to one
+        /// of its campuses.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::company::Ein;
+        /// assert_eq!("68-0589815", rng.gen::<Ein>().to_string());
+        /// assert_eq!("75-6468579", rng.gen::<Ein>().to_string());
+        /// # let prefixes: Vec<String> = (1..=99)
+        /// #     .filter(|n| ![7, 8, 9, 17, 18, 19, 28, 29, 49, 69, 70, 78, 79, 89, 96, 97].contains(n))
+        /// #     .map(|n| format!("{:02}", n))
+        /// #     .collect();
+        /// # assert_eq!(83, prefixes.len());
+        /// # for _ in 0..10000 {
+        /// #     let s = rng.gen::<Ein>().to_string();
+        /// #     assert_eq!(10, s.len(), "{}", s);
+        /// #     assert!(prefixes.contains(&s[..2].to_string()), "{}", s);
+        /// # }
+        /// ```
+        pub struct Ein(String);
+        faker_impl_from_templates! {
+            Ein;
+
+            "{}-{}{}{}{}{}{}{}", EinPrefix, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates an Employer Identification Number as nine digits without a
+        /// hyphen, such as "123456789".
+        ///
+        /// Given the same RNG, this generates the same digits as [`Ein`] does,
+        /// so they follow the same rules.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::company::EinDigits;
+        /// assert_eq!("680589815", rng.gen::<EinDigits>().to_string());
+        /// assert_eq!("756468579", rng.gen::<EinDigits>().to_string());
+        /// ```
+        pub struct EinDigits(String);
+        faker_impl_from_templates! {
+            EinDigits;
+
+            "{}{}{}{}{}{}{}{}", EinPrefix, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+
+    /// Generators for jobs and the parts of job titles.
+    pub mod jobs {
+        /// Generates a seniority level, such as "Senior" or "Lead".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::jobs::Seniority;
+        /// assert_eq!("Senior Principal", rng.gen::<Seniority>().to_string());
+        /// assert_eq!("Interim", rng.gen::<Seniority>().to_string());
+        /// ```
+        pub struct Seniority(&'static str);
+        faker_impl_from_file!(Seniority, "data/en_us/job_seniorities");
+
+        /// Generates a word that describes the scope or focus of a job, such as
+        /// "Global" or "Customer".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::jobs::JobDescriptor;
+        /// assert_eq!("Federal", rng.gen::<JobDescriptor>().to_string());
+        /// assert_eq!("Dynamic", rng.gen::<JobDescriptor>().to_string());
+        /// ```
+        pub struct JobDescriptor(&'static str);
+        faker_impl_from_file!(JobDescriptor, "data/en_us/job_descriptors");
+
+        /// Generates a field of work, such as "Marketing" or "Infrastructure".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::jobs::JobField;
+        /// assert_eq!("Operations", rng.gen::<JobField>().to_string());
+        /// assert_eq!("Data", rng.gen::<JobField>().to_string());
+        /// ```
+        pub struct JobField(&'static str);
+        faker_impl_from_file!(JobField, "data/en_us/job_fields");
+
+        /// Generates the role part of a job title, such as "Engineer" or
+        /// "Analyst".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::jobs::JobRole;
+        /// assert_eq!("Consultant", rng.gen::<JobRole>().to_string());
+        /// assert_eq!("Researcher", rng.gen::<JobRole>().to_string());
+        /// ```
+        pub struct JobRole(&'static str);
+        faker_impl_from_file!(JobRole, "data/en_us/job_roles");
+
+        /// Generates a job title, such as "Senior Marketing Analyst".
+        ///
+        /// Most job titles are a [`Seniority`], a [`JobField`], and a
+        /// [`JobRole`]. Some have a [`JobDescriptor`] in place of the
+        /// seniority, such as "Global Marketing Analyst", and the rest leave
+        /// out the seniority, such as "Marketing Analyst".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::jobs::JobTitle;
+        /// assert_eq!("Area Intranet Representative", rng.gen::<JobTitle>().to_string());
+        /// assert_eq!("Expert Web Inspector", rng.gen::<JobTitle>().to_string());
+        /// ```
+        ///
+        /// The parts can also be used on their own, to make other kinds of
+        /// titles:
+        ///
+        /// ```
+        /// use faker_rand::faker_impl_from_templates;
+        /// use faker_rand::en_us::jobs::{JobField, Seniority};
+        ///
+        /// struct Department(String);
+        /// faker_impl_from_templates! {
+        ///     Department;
+        ///
+        ///     "{} {} Department", Seniority, JobField;
+        /// }
+        ///
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// assert_eq!("Apprentice Web Department", rng.gen::<Department>().to_string());
+        /// ```
+        pub struct JobTitle(String);
+        faker_impl_from_templates! {
+            JobTitle;
+
+            3 => "{} {} {}", Seniority, JobField, JobRole;
+            "{} {} {}", JobDescriptor, JobField, JobRole;
+            "{} {}", JobField, JobRole;
+        }
+    }
+
+    /// Generators for products and their categories, such as for an online
+    /// store.
+    pub mod commerce {
+        struct ProductAdjective(&'static str);
+        faker_impl_from_file!(ProductAdjective, "data/en_us/product_adjectives");
+
+        struct ProductMaterial(&'static str);
+        faker_impl_from_file!(ProductMaterial, "data/en_us/product_materials");
+
+        struct ProductNoun(&'static str);
+        faker_impl_from_file!(ProductNoun, "data/en_us/product_nouns");
+
+        /// Generates a product name, such as "Ergonomic Wooden Chair".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::commerce::ProductName;
+        /// assert_eq!("Ergonomic Frozen Chips", rng.gen::<ProductName>().to_string());
+        /// ```
+        ///
+        /// Combined with [`finance::Price`](crate::finance::Price), this can
+        /// make a whole product listing:
+        ///
+        /// ```
+        /// use faker_rand::faker_impl_from_templates;
+        /// use faker_rand::en_us::commerce::{ProductCategory, ProductName};
+        /// use faker_rand::finance::Price;
+        ///
+        /// struct Listing(String);
+        /// faker_impl_from_templates! {
+        ///     Listing;
+        ///
+        ///     "{} ({}): ${}", ProductName, ProductCategory, Price<1, 200>;
+        /// }
+        ///
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// assert_eq!("Elegant Frozen Chips (Tools): $160.96", rng.gen::<Listing>().to_string());
+        /// ```
+        pub struct ProductName(String);
+        faker_impl_from_templates! {
+            ProductName;
+
+            "{} {} {}", ProductAdjective, ProductMaterial, ProductNoun;
+        }
+
+        /// Generates a product category, such as "Electronics".
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::commerce::ProductCategory;
+        /// assert_eq!("Movies", rng.gen::<ProductCategory>().to_string());
+        /// ```
+        pub struct ProductCategory(&'static str);
+        faker_impl_from_file!(ProductCategory, "data/en_us/product_categories");
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    pub mod internet {
+        use super::names::{FirstName, LastName};
+        use crate::util::{AsciiDigit, AsciiLowercase, NumberInRange, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastName>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/en_us/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::internet::Domain;
+        /// assert_eq!("thiel.name", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::internet::Username;
+        /// assert_eq!("odietrich48", rng.gen::<Username>().to_string());
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::internet::Email;
+        /// assert_eq!("odietrich48@thompson.net", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+
+        struct UserAgentPlatform(&'static str);
+        faker_impl_from_file!(UserAgentPlatform, "data/en_us/user_agent_platforms");
+
+        /// Generates a web browser's user agent string.
+        ///
+        /// The user agent is that of a recent version of Chrome, Firefox, or
+        /// Safari, on Windows, macOS, or Linux. Safari is only ever on macOS.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::internet::UserAgent;
+        /// assert_eq!(
+        ///     "Mozilla/5.0 (X11; Linux x86_64; rv:125.0) Gecko/20100101 Firefox/125.0",
+        ///     rng.gen::<UserAgent>().to_string()
+        /// );
+        /// assert_eq!(
+        ///     "Mozilla/5.0 (X11; Fedora; Linux x86_64; rv:111.0) Gecko/20100101 Firefox/111.0",
+        ///     rng.gen::<UserAgent>().to_string()
+        /// );
+        /// assert_eq!(
+        ///     "Mozilla/5.0 (X11; Fedora; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.6802.99 Safari/537.36",
+        ///     rng.gen::<UserAgent>().to_string()
+        /// );
+        /// ```
+        pub struct UserAgent(String);
+        faker_impl_from_templates! {
+            UserAgent;
+
+            3 => "Mozilla/5.0 ({}) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/{}.0.{}.{} Safari/537.36", UserAgentPlatform, NumberInRange<110, 130>, NumberInRange<5000, 6999>, NumberInRange<0, 199>;
+
+            let version = NumberInRange<110, 130>;
+            "Mozilla/5.0 ({}; rv:{version}.0) Gecko/20100101 Firefox/{version}.0", UserAgentPlatform;
+
+            "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/{}.{} Safari/605.1.15", NumberInRange<15, 17>, NumberInRange<0, 6>;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::{AsciiDigit, NumberInRange};
+
+        // The first digit of a NANP area code or exchange code, which is never
+        // 0 or 1.
+        type LeadingDigit = NumberInRange<2, 9>;
+
+        /// Generates a phone number.
+        ///
+        /// As in the North American Numbering Plan, neither the area code nor
+        /// the exchange code starts with 0 or 1.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::phones::PhoneNumber;
+        /// assert_eq!("(258) 981-5364", rng.gen::<PhoneNumber>().to_string());
+        /// # for _ in 0..10000 {
+        /// #     let n = rng.gen::<PhoneNumber>().to_string();
+        /// #     let n = n.as_bytes();
+        /// #     assert!((b'2'..=b'9').contains(&n[1]), "{}", String::from_utf8_lossy(n));
+        /// #     assert!((b'2'..=b'9').contains(&n[6]), "{}", String::from_utf8_lossy(n));
+        /// # }
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "({}{}{}) {}{}{}-{}{}{}{}", LeadingDigit, AsciiDigit, AsciiDigit, LeadingDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+
+    /// Generators for government-issued identifiers.
+    pub mod identifiers {
+        use rand::distributions::{Distribution, Standard};
+        use rand::Rng;
+
+        /// Generates a Social Security number, such as "123-45-6789".
+        ///
+        /// The area number (the first three digits) is never 000, 666, or 900
+        /// through 999. The group number (the middle two digits) is never 00,
+        /// and the serial number (the last four digits) is never 0000. These
+        /// are the numbers that the Social Security Administration never
+        /// assigns.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::identifiers::Ssn;
+        /// assert_eq!("637-73-5074", rng.gen::<Ssn>().to_string());
+        /// assert_eq!("628-56-1954", rng.gen::<Ssn>().to_string());
+        /// # for _ in 0..100000 {
+        /// #     let s = rng.gen::<Ssn>().to_string();
+        /// #     let parts: Vec<&str> = s.split('-').collect();
+        /// #     assert_eq!(vec![3, 2, 4], parts.iter().map(|p| p.len()).collect::<Vec<_>>(), "{}", s);
+        /// #     assert!(!["000", "666"].contains(&parts[0]) && !parts[0].starts_with('9'), "{}", s);
+        /// #     assert!(parts[1] != "00" && parts[2] != "0000", "{}", s);
+        /// # }
+        /// ```
+        pub struct Ssn(String);
+
+        impl Distribution<Ssn> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ssn {
+                let (area, group, serial) = ssn_parts(rng);
+                Ssn(format!("{:03}-{:02}-{:04}", area, group, serial))
+            }
+        }
+
+        __faker_impl_newtype!([] Ssn);
+
+        /// Generates a Social Security number as nine digits without hyphens,
+        /// such as "123456789".
+        ///
+        /// Given the same RNG, this generates the same digits as [`Ssn`] does,
+        /// so they follow the same rules.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::identifiers::SsnDigits;
+        /// assert_eq!("637735074", rng.gen::<SsnDigits>().to_string());
+        /// assert_eq!("628561954", rng.gen::<SsnDigits>().to_string());
+        /// ```
+        pub struct SsnDigits(String);
+
+        impl Distribution<SsnDigits> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SsnDigits {
+                let (area, group, serial) = ssn_parts(rng);
+                SsnDigits(format!("{:03}{:02}{:04}", area, group, serial))
+            }
+        }
+
+        __faker_impl_newtype!([] SsnDigits);
+
+        fn ssn_parts<R: Rng + ?Sized>(rng: &mut R) -> (u32, u32, u32) {
+            // Areas run from 001 to 899, skipping 666.
+            let mut area = rng.gen_range(1..899);
+            if area >= 666 {
+                area += 1;
+            }
+
+            (area, rng.gen_range(1..=99), rng.gen_range(1..=9999))
+        }
+    }
+
+    /// Generators for payment cards, including their holders' names.
+    ///
+    /// Payment data that doesn't depend on the locale, such as card numbers, is
+    /// generated by [`crate::payments`].
+    pub mod payments {
+        use super::names::FullName;
+        use crate::payments::{sample_expiry, Scheme};
+        use crate::util::ToUppercase;
+        use rand::distributions::{Distribution, Standard};
+        use rand::Rng;
+
+        /// Generates the details of a payment card: its holder's name, number,
+        /// expiry date, and security code.
+        ///
+        /// The output has four lines, in this order:
+        ///
+        /// 1. The cardholder's name, which is an uppercased [`FullName`].
+        /// 2. The card number, generated in the same way as
+        ///    [`CreditCardNumber`](crate::payments::CreditCardNumber).
+        /// 3. The expiry date, generated in the same way as
+        ///    [`CardExpiry`](crate::payments::CardExpiry), with the same
+        ///    `FROM_YEAR` parameter.
+        /// 4. The card security code, which is four digits for American Express
+        ///    cards, and three digits for the others.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_us::payments::CreditCard;
+        /// assert_eq!(
+        ///     "SARAH SIMONIS DVM\n6011 9148 9745 6303\n10/30\n019",
+        ///     rng.gen::<CreditCard>().to_string()
+        /// );
+        /// assert_eq!(
+        ///     "KING MCDERMOTT PHD\n6011 1577 0959 9973\n07/30\n664",
+        ///     rng.gen::<CreditCard>().to_string()
+        /// );
+        /// # for _ in 0..10000 {
+        /// #     let s = rng.gen::<CreditCard>().to_string();
+        /// #     let lines: Vec<&str> = s.lines().collect();
+        /// #     assert_eq!(4, lines.len(), "{}", s);
+        /// #     assert_eq!(lines[0].to_uppercase(), lines[0], "{}", s);
+        /// #     let cvv_len = if lines[1].starts_with('3') { 4 } else { 3 };
+        /// #     assert_eq!(5, lines[2].len(), "{}", s);
+        /// #     assert_eq!(cvv_len, lines[3].len(), "{}", s);
+        /// #     assert!(lines[3].bytes().all(|b| b.is_ascii_digit()), "{}", s);
+        /// # }
+        /// ```
+        pub struct CreditCard<const FROM_YEAR: i64 = 2026>(String);
+
+        impl<const FROM_YEAR: i64> Distribution<CreditCard<FROM_YEAR>> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CreditCard<FROM_YEAR> {
+                let name = rng.gen::<ToUppercase<FullName>>().to_string();
+                let scheme: Scheme = rng.gen();
+                let number = scheme.sample_grouped_number(rng);
+                let expiry = sample_expiry(rng, FROM_YEAR);
+                let cvv: String = (0..scheme.cvv_len())
+                    .map(|_| (b'0' + rng.gen_range(0..10)) as char)
+                    .collect();
+
+                CreditCard(format!("{}\n{}\n{}\n{}", name, number, expiry, cvv))
+            }
+        }
+
+        __faker_impl_newtype!([const FROM_YEAR: i64] CreditCard<FROM_YEAR>);
+    }
+}
+
+/// Localized generators for French as spoken in France (`fr-FR`).
+pub mod fr_fr {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    pub mod names {
+        /// Generates a first name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::names::FirstName;
+        /// assert_eq!("Mahaut", rng.gen::<FirstName>().to_string());
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/fr_fr/first_names");
+
+        /// Generates a last name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::names::LastName;
+        /// assert_eq!("GUILLOT", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/fr_fr/last_names");
+
+        /// Generates a name prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::names::NamePrefix;
+        /// assert_eq!("Dr", rng.gen::<NamePrefix>().to_string());
+        /// ```
+        pub struct NamePrefix(&'static str);
+        faker_impl_from_file!(NamePrefix, "data/fr_fr/name_prefixes");
+
+        /// Generates a full name, including possibly a prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::names::FullName;
+        /// assert_eq!("Mlle Gisèle MARTINEZ", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            "{} {}", FirstName, LastName;
+            "{} {} {}", NamePrefix, FirstName, LastName;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postal codes, etc.).
+    pub mod addresses {
+        use super::names::FullName;
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit};
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::CityName;
+        /// assert_eq!("Levallois-Perret", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(&'static str);
+        faker_impl_from_file!(CityName, "data/fr_fr/city_names");
+
+        struct StreetPrefix(&'static str);
+        faker_impl_from_file!(StreetPrefix, "data/fr_fr/street_prefixes");
+
+        struct StreetSuffix(&'static str);
+        faker_impl_from_file!(StreetSuffix, "data/fr_fr/street_suffixes");
+
+        /// Generates a street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::StreetName;
+        /// assert_eq!("Passage de Seine", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(String);
+        faker_impl_from_templates! {
+            StreetName;
+
+            "{} {}", StreetPrefix, StreetSuffix;
+        }
+
+        struct BuildingNumber(String);
+        faker_impl_from_templates! {
+            BuildingNumber;
+
+            "{}", NonZeroAsciiDigit;
+            "{}{}", NonZeroAsciiDigit, AsciiDigit;
+            "{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a street address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::StreetAddress;
+        /// assert_eq!("64 Place de Montmorency", rng.gen::<StreetAddress>().to_string());
+        /// # for _ in 0..1000 {
+        /// #     assert!(!rng.gen::<StreetAddress>().to_string().starts_with('0'));
+        /// # }
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{} {}", BuildingNumber, StreetName;
+        }
+
+        /// Generates a secondary address (e.g. an apartment number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::SecondaryAddress;
+        /// assert_eq!("7 étage", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "Apt. {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
+            "{} étage", AsciiDigit;
+        }
+
+        /// Generates a first-level administrative division (e.g. one of the
+        /// *régions* of France).
+        ///
+        /// Currently, this will generate only one of the 13 metropolitan
+        /// regions of France. This may be changed in a future minor version of
+        /// this crate.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::Division;
+        /// assert_eq!("Nouvelle-Aquitaine", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/fr_fr/divisions");
+
+        /// Generates a postal code.
+        ///
+        /// No guarantee is made that the first two digits correspond to a
+        /// correct department.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::PostalCode;
+        /// assert_eq!("05898", rng.gen::<PostalCode>().to_string());
+        /// ```
+        pub struct PostalCode(String);
+        faker_impl_from_templates! {
+            PostalCode;
+
+            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a full postal address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::addresses::Address;
+        /// assert_eq!(
+        ///     "Mlle Lucille MOREAU\nApt. 489\n96 Quai Saint-Jacques\n05764 Saint-Nazaire\nFRANCE\n",
+        ///     rng.gen::<Address>().to_string()
+        /// );
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "{}\n{}\n{} {}\nFRANCE\n", FullName, StreetAddress, PostalCode, CityName;
+            "{}\n{}\n{}\n{} {}\nFRANCE\n", FullName, SecondaryAddress, StreetAddress, PostalCode, CityName;
+        }
+    }
+
+    /// Generators for company names.
+    pub mod company {
+        use super::names::FirstName;
+
+        struct CompanySuffix(&'static str);
+        faker_impl_from_file!(CompanySuffix, "data/fr_fr/company_suffixes");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::company::CompanyName;
+        /// assert_eq!("Lucille SARL", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "{} {}", FirstName, CompanySuffix;
+        }
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    pub mod internet {
+        use super::names::{FirstName, LastName};
+        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastName>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/fr_fr/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::internet::Domain;
+        /// assert_eq!("renard.net", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::internet::Username;
+        /// assert_eq!("omartinez48", rng.gen::<Username>().to_string());
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::internet::Email;
+        /// assert_eq!("omartinez48@poirier.net", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::AsciiDigit;
+
+        /// Generates a phone number.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::fr_fr::phones::PhoneNumber;
+        /// assert_eq!("00 58 98 15 36", rng.gen::<PhoneNumber>().to_string());
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "0{} {}{} {}{} {}{} {}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+}
+
+/// Localized generators for German as spoken in Germany (`de-DE`).
+pub mod de_de {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    pub mod names {
+        /// Generates a first name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::names::FirstName;
+        /// assert_eq!("Bernd", rng.gen::<FirstName>().to_string());
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/de_de/first_names");
+
+        /// Generates a last name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::names::LastName;
+        /// assert_eq!("Schneider", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/de_de/last_names");
+
+        /// Generates a name prefix (an academic title, e.g. "Dr.").
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::names::NamePrefix;
+        /// assert_eq!("Dr.", rng.gen::<NamePrefix>().to_string());
+        /// ```
+        pub struct NamePrefix(&'static str);
+        faker_impl_from_file!(NamePrefix, "data/de_de/name_prefixes");
+
+        /// Generates a full name, including possibly a prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::names::FullName;
+        /// assert_eq!("Prof. Dr. Lina Graf", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            "{} {}", FirstName, LastName;
+            "{} {} {}", NamePrefix, FirstName, LastName;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postal codes, etc.).
+    pub mod addresses {
+        use super::names::FullName;
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit};
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::CityName;
+        /// assert_eq!("München", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(&'static str);
+        faker_impl_from_file!(CityName, "data/de_de/city_names");
+
+        struct StreetRoot(&'static str);
+        faker_impl_from_file!(StreetRoot, "data/de_de/street_roots");
+
+        struct StreetSuffix(&'static str);
+        faker_impl_from_file!(StreetSuffix, "data/de_de/street_suffixes");
+
+        /// Generates a street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::StreetName;
+        /// assert_eq!("Mozartstraße", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(String);
+        faker_impl_from_templates! {
+            StreetName;
+
+            "{}{}", StreetRoot, StreetSuffix;
+        }
+
+        struct BuildingNumber(String);
+        faker_impl_from_templates! {
+            BuildingNumber;
+
+            "{}", NonZeroAsciiDigit;
+            "{}{}", NonZeroAsciiDigit, AsciiDigit;
+            "{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a street address.
+        ///
+        /// In Germany, the building number comes after the street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::StreetAddress;
+        /// assert_eq!("Schulallee 89", rng.gen::<StreetAddress>().to_string());
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{} {}", StreetName, BuildingNumber;
+        }
+
+        /// Generates a secondary address (e.g. an apartment number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::SecondaryAddress;
+        /// assert_eq!("6. OG", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "Wohnung {}{}", NonZeroAsciiDigit, AsciiDigit;
+            "{}. OG", NonZeroAsciiDigit;
+        }
+
+        /// Generates a first-level administrative division (i.e. one of the 16
+        /// *Länder* of Germany).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::Division;
+        /// assert_eq!("Saarland", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/de_de/divisions");
+
+        /// Generates a postal code (a five-digit *Postleitzahl*).
+        ///
+        /// No guarantee is made that the postal code is actually in use.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::PostalCode;
+        /// assert_eq!("05898", rng.gen::<PostalCode>().to_string());
+        /// ```
+        pub struct PostalCode(String);
+        faker_impl_from_templates! {
+            PostalCode;
+
+            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a full postal address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::addresses::Address;
+        /// assert_eq!("Dr. Lena Simon\nSchulring 60\n1. OG\n46857 Stuttgart\nDEUTSCHLAND\n", rng.gen::<Address>().to_string());
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "{}\n{}\n{} {}\nDEUTSCHLAND\n", FullName, StreetAddress, PostalCode, CityName;
+            "{}\n{}\n{}\n{} {}\nDEUTSCHLAND\n", FullName, StreetAddress, SecondaryAddress, PostalCode, CityName;
+        }
+    }
+
+    /// Generators for company names.
+    pub mod company {
+        use super::names::LastName;
+
+        struct CompanySuffix(&'static str);
+        faker_impl_from_file!(CompanySuffix, "data/de_de/company_suffixes");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::company::CompanyName;
+        /// assert_eq!("Schreiber & Maier AG", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "{} {}", LastName, CompanySuffix;
+            "{} & {} {}", LastName, LastName, CompanySuffix;
+        }
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    pub mod internet {
+        use super::names::{FirstName, LastName};
+        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastName>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/de_de/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::internet::Domain;
+        /// assert_eq!("vogt.org", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::internet::Username;
+        /// assert_eq!("ograf48", rng.gen::<Username>().to_string());
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::internet::Email;
+        /// assert_eq!("ograf48@vogt.info", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit};
+
+        /// Generates a phone number, made up of a four-digit area code and a
+        /// seven-digit subscriber number.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::de_de::phones::PhoneNumber;
+        /// assert_eq!("0705 8981536", rng.gen::<PhoneNumber>().to_string());
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "0{}{}{} {}{}{}{}{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit, NonZeroAsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+}
+
+/// Localized generators for Spanish as spoken in Spain (`es-ES`).
+pub mod es_es {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    pub mod names {
+        /// Generates a first name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::names::FirstName;
+        /// assert_eq!("Mercedes", rng.gen::<FirstName>().to_string());
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/es_es/first_names");
+
+        /// Generates a last name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::names::LastName;
+        /// assert_eq!("Lozano", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/es_es/last_names");
+
+        /// Generates a name prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::names::NamePrefix;
+        /// assert_eq!("Dr.", rng.gen::<NamePrefix>().to_string());
+        /// ```
+        pub struct NamePrefix(&'static str);
+        faker_impl_from_file!(NamePrefix, "data/es_es/name_prefixes");
+
+        /// Generates a full name, including possibly a prefix.
+        ///
+        /// Most full names have two last names, as is customary in Spain, but
+        /// some have only one.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::names::FullName;
+        /// assert_eq!("Mónica Méndez", rng.gen::<FullName>().to_string());
+        /// assert_eq!("Alicia Moya", rng.gen::<FullName>().to_string());
+        /// assert_eq!("Jesús Cabrera Martínez", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            3 => "{} {} {}", FirstName, LastName, LastName;
+            1 => "{} {}", FirstName, LastName;
+            1 => "{} {} {} {}", NamePrefix, FirstName, LastName, LastName;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postal codes, etc.).
+    pub mod addresses {
+        use super::names::FullName;
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit, NumberInRange, ZeroPad};
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::CityName;
+        /// assert_eq!("Zaragoza", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(&'static str);
+        faker_impl_from_file!(CityName, "data/es_es/city_names");
+
+        struct StreetPrefix(&'static str);
+        faker_impl_from_file!(StreetPrefix, "data/es_es/street_prefixes");
+
+        struct StreetSuffix(&'static str);
+        faker_impl_from_file!(StreetSuffix, "data/es_es/street_suffixes");
+
+        /// Generates a street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::StreetName;
+        /// assert_eq!("Calle del Generalife", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(String);
+        faker_impl_from_templates! {
+            StreetName;
+
+            "{} {}", StreetPrefix, StreetSuffix;
+        }
+
+        struct BuildingNumber(String);
+        faker_impl_from_templates! {
+            BuildingNumber;
+
+            "{}", NonZeroAsciiDigit;
+            "{}{}", NonZeroAsciiDigit, AsciiDigit;
+            "{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a street address.
+        ///
+        /// In Spain, the building number comes after the street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::StreetAddress;
+        /// assert_eq!("Ronda de los Olivos, 599", rng.gen::<StreetAddress>().to_string());
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{}, {}", StreetName, BuildingNumber;
+        }
+
+        struct Door(&'static str);
+        faker_impl_from_words!(Door, ["A", "B", "C", "D", "izda.", "dcha."]);
+
+        /// Generates a secondary address (i.e. a floor and door number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::SecondaryAddress;
+        /// assert_eq!("7º A", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "{}º {}", NonZeroAsciiDigit, Door;
+        }
+
+        /// Generates a first-level administrative division (i.e. one of the 17
+        /// *comunidades autónomas* of Spain).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::Division;
+        /// assert_eq!("Comunidad de Madrid", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/es_es/divisions");
+
+        /// Generates a postal code.
+        ///
+        /// The first two digits are always a valid province code (01 to 52),
+        /// but no guarantee is made that the postal code is actually in use.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::PostalCode;
+        /// assert_eq!("37058", rng.gen::<PostalCode>().to_string());
+        /// # for _ in 0..1000 {
+        /// #     let code: u32 = rng.gen::<PostalCode>()[..2].parse().unwrap();
+        /// #     assert!((1..=52).contains(&code));
+        /// # }
+        /// ```
+        pub struct PostalCode(String);
+        faker_impl_from_templates! {
+            PostalCode;
+
+            "{}{}{}{}", ZeroPad<NumberInRange<1, 52>, 2>, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a full postal address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::addresses::Address;
+        /// assert_eq!("Iván Sánchez\nCarretera de Miguel de Unamuno, 253, 6º A\n23685 Ciudad Real\nESPAÑA\n", rng.gen::<Address>().to_string());
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "{}\n{}\n{} {}\nESPAÑA\n", FullName, StreetAddress, PostalCode, CityName;
+            "{}\n{}, {}\n{} {}\nESPAÑA\n", FullName, StreetAddress, SecondaryAddress, PostalCode, CityName;
+        }
+    }
+
+    /// Generators for company names.
+    pub mod company {
+        use super::names::LastName;
+
+        struct CompanySuffix(&'static str);
+        faker_impl_from_file!(CompanySuffix, "data/es_es/company_suffixes");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::company::CompanyName;
+        /// assert_eq!("Méndez y Márquez S.L.U.", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "{} {}", LastName, CompanySuffix;
+            "{} y {} {}", LastName, LastName, CompanySuffix;
+        }
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    pub mod internet {
+        use super::names::{FirstName, LastName};
+        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastName>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/es_es/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::internet::Domain;
+        /// assert_eq!("moya.info", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::internet::Username;
+        /// assert_eq!("ogutierrez48", rng.gen::<Username>().to_string());
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::internet::Email;
+        /// assert_eq!("ogutierrez48@roman.net", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::AsciiDigit;
+
+        /// Generates a phone number, either a mobile number (starting with 6)
+        /// or a landline number (starting with 9).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::es_es::phones::PhoneNumber;
+        /// assert_eq!("975 54 89 96", rng.gen::<PhoneNumber>().to_string());
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "6{}{} {}{}{} {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+            "9{}{} {}{} {}{} {}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+}
+
+/// Localized generators for Japanese as spoken in Japan (`ja-JP`).
+pub mod ja_jp {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    ///
+    /// Names are written in kanji (or occasionally kana) by default. The
+    /// `Romaji` generators write names in the Latin alphabet instead, using
+    /// Hepburn romanization without macrons.
+    pub mod names {
+        /// Generates a first (given) name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::FirstName;
+        /// assert_eq!("由美", rng.gen::<FirstName>().to_string());
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/ja_jp/first_names");
+
+        /// Generates a romanized first (given) name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::FirstNameRomaji;
+        /// assert_eq!("Yumi", rng.gen::<FirstNameRomaji>().to_string());
+        /// ```
+        pub struct FirstNameRomaji(&'static str);
+        faker_impl_from_file!(FirstNameRomaji, "data/ja_jp/first_names_romaji");
+
+        /// Generates a last (family) name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::LastName;
+        /// assert_eq!("近藤", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/ja_jp/last_names");
+
+        /// Generates a romanized last (family) name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::LastNameRomaji;
+        /// assert_eq!("Kondo", rng.gen::<LastNameRomaji>().to_string());
+        /// ```
+        pub struct LastNameRomaji(&'static str);
+        faker_impl_from_file!(LastNameRomaji, "data/ja_jp/last_names_romaji");
+
+        /// Generates a full name.
+        ///
+        /// As is customary in Japan, the family name comes first.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::FullName;
+        /// assert_eq!("田中 葵", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            "{} {}", LastName, FirstName;
+        }
+
+        /// Generates a romanized full name.
+        ///
+        /// Like [`FullName`], the family name comes first.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::names::FullNameRomaji;
+        /// assert_eq!("Tanaka Aoi", rng.gen::<FullNameRomaji>().to_string());
+        /// ```
+        pub struct FullNameRomaji(String);
+        faker_impl_from_templates! {
+            FullNameRomaji;
+
+            "{} {}", LastNameRomaji, FirstNameRomaji;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postal codes, etc.).
+    pub mod addresses {
+        use super::names::FullName;
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit, NumberInRange};
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::CityName;
+        /// assert_eq!("富山市", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(&'static str);
+        faker_impl_from_file!(CityName, "data/ja_jp/city_names");
+
+        /// Generates a street name.
+        ///
+        /// Most Japanese addresses identify blocks within a town rather than
+        /// buildings along a street, so this generates the name of a town
+        /// (*chō*) instead.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::StreetName;
+        /// assert_eq!("寿町", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(&'static str);
+        faker_impl_from_file!(StreetName, "data/ja_jp/town_names");
+
+        /// Generates a street address, made up of a town name and a
+        /// *chōme*-*banchi*-*gō* block number.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::StreetAddress;
+        /// assert_eq!("中央8丁目29番17号", rng.gen::<StreetAddress>().to_string());
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{}{}丁目{}番{}号", StreetName, NumberInRange<1, 9>, NumberInRange<1, 30>, NumberInRange<1, 20>;
+        }
+
+        /// Generates a secondary address (i.e. a room number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::SecondaryAddress;
+        /// assert_eq!("708号室", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "{}{}{}号室", NonZeroAsciiDigit, AsciiDigit, NonZeroAsciiDigit;
+        }
+
+        /// Generates a first-level administrative division (i.e. one of the 47
+        /// prefectures of Japan).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::Division;
+        /// assert_eq!("広島県", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/ja_jp/divisions");
+
+        /// Generates a postal code, formatted as `NNN-NNNN`.
+        ///
+        /// No guarantee is made that the postal code is actually in use.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::PostalCode;
+        /// assert_eq!("058-9815", rng.gen::<PostalCode>().to_string());
+        /// ```
+        pub struct PostalCode(String);
+        faker_impl_from_templates! {
+            PostalCode;
+
+            "{}{}{}-{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a full postal address.
+        ///
+        /// As is customary in Japan, the address goes from the largest region
+        /// to the smallest, and the recipient's name comes last.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::addresses::Address;
+        /// assert_eq!("〒058-9815\n石川県高松市川口町6丁目18番18号 807号室\n山崎 直美\n", rng.gen::<Address>().to_string());
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "〒{}\n{}{}{}\n{}\n", PostalCode, Division, CityName, StreetAddress, FullName;
+            "〒{}\n{}{}{} {}\n{}\n", PostalCode, Division, CityName, StreetAddress, SecondaryAddress, FullName;
+        }
+    }
+
+    /// Generators for company names.
+    pub mod company {
+        use super::names::LastName;
+
+        struct CompanyIndustry(&'static str);
+        faker_impl_from_file!(CompanyIndustry, "data/ja_jp/company_industries");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::company::CompanyName;
+        /// assert_eq!("有限会社橋本不動産", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "株式会社{}{}", LastName, CompanyIndustry;
+            "{}{}株式会社", LastName, CompanyIndustry;
+            "有限会社{}{}", LastName, CompanyIndustry;
+        }
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    ///
+    /// These generators are built from romanized names.
+    /// [`ToAsciiLowercase`][`crate::util::ToAsciiLowercase`] would transliterate
+    /// kanji names using their Chinese readings, which don't look like Japanese
+    /// names.
+    pub mod internet {
+        use super::names::{FirstNameRomaji, LastNameRomaji};
+        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastNameRomaji>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/ja_jp/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::internet::Domain;
+        /// assert_eq!("nakajima.com", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::internet::Username;
+        /// assert_eq!("okato48", rng.gen::<Username>().to_string());
+        /// # for _ in 0..1000 {
+        /// #     let username = rng.gen::<Username>();
+        /// #     assert!(username.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+        /// # }
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastNameRomaji>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastNameRomaji>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastNameRomaji>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstNameRomaji>, ToAsciiLowercase<LastNameRomaji>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::internet::Email;
+        /// assert_eq!("okato48@kaneko.ne.jp", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit};
+
+        /// Generates a phone number, formatted as `0X-XXXX-XXXX`.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::ja_jp::phones::PhoneNumber;
+        /// assert_eq!("07-0589-8153", rng.gen::<PhoneNumber>().to_string());
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "0{}-{}{}{}{}-{}{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+}
+
+/// Localized generators for English as spoken in the United Kingdom
+/// (`en-GB`).
+pub mod en_gb {
+    /// Generators for the names of individuals (e.g., first, last, or full
+    /// names).
+    pub mod names {
+        /// Generates a first name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::names::FirstName;
+        /// assert_eq!("Sophia", rng.gen::<FirstName>().to_string());
+        /// ```
+        pub struct FirstName(&'static str);
+        faker_impl_from_file!(FirstName, "data/en_gb/first_names");
+
+        /// Generates a last name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::names::LastName;
+        /// assert_eq!("Price", rng.gen::<LastName>().to_string());
+        /// ```
+        pub struct LastName(&'static str);
+        faker_impl_from_file!(LastName, "data/en_gb/last_names");
+
+        /// Generates a name prefix.
+        ///
+        /// As is usual in British English, abbreviated prefixes don't end in a
+        /// full stop.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::names::NamePrefix;
+        /// assert_eq!("Miss", rng.gen::<NamePrefix>().to_string());
+        /// ```
+        pub struct NamePrefix(&'static str);
+        faker_impl_from_file!(NamePrefix, "data/en_gb/name_prefixes");
+
+        /// Generates a full name, including possibly a prefix.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::names::FullName;
+        /// assert_eq!("Ms Ruby Hall", rng.gen::<FullName>().to_string());
+        /// ```
+        pub struct FullName(String);
+        faker_impl_from_templates! {
+            FullName;
+
+            "{} {}", FirstName, LastName;
+            "{} {} {}", NamePrefix, FirstName, LastName;
+        }
+    }
+
+    /// Generators for postal addresses and their constituent parts (e.g. city
+    /// names, postcodes, etc.).
+    pub mod addresses {
+        use super::names::FullName;
+        use crate::util::{AsciiDigit, NonZeroAsciiDigit, ToUppercase};
+        use rand::distributions::{Distribution, Standard};
+        use rand::seq::SliceRandom;
+        use rand::Rng;
+
+        /// Generates a city name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::CityName;
+        /// assert_eq!("Carlisle", rng.gen::<CityName>().to_string());
+        /// ```
+        pub struct CityName(&'static str);
+        faker_impl_from_file!(CityName, "data/en_gb/city_names");
+
+        struct StreetRoot(&'static str);
+        faker_impl_from_file!(StreetRoot, "data/en_gb/street_roots");
+
+        struct StreetSuffix(&'static str);
+        faker_impl_from_file!(StreetSuffix, "data/en_gb/street_suffixes");
+
+        /// Generates a street name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::StreetName;
+        /// assert_eq!("Stanley Way", rng.gen::<StreetName>().to_string());
+        /// ```
+        pub struct StreetName(String);
+        faker_impl_from_templates! {
+            StreetName;
+
+            "{} {}", StreetRoot, StreetSuffix;
+        }
+
+        struct BuildingNumber(String);
+        faker_impl_from_templates! {
+            BuildingNumber;
+
+            "{}", NonZeroAsciiDigit;
+            "{}{}", NonZeroAsciiDigit, AsciiDigit;
+            "{}{}{}", NonZeroAsciiDigit, AsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a street address.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::StreetAddress;
+        /// assert_eq!("64 Grosvenor Crescent", rng.gen::<StreetAddress>().to_string());
+        /// ```
+        pub struct StreetAddress(String);
+        faker_impl_from_templates! {
+            StreetAddress;
+
+            "{} {}", BuildingNumber, StreetName;
+        }
+
+        /// Generates a secondary address (e.g. a flat number).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::SecondaryAddress;
+        /// assert_eq!("Flat 64", rng.gen::<SecondaryAddress>().to_string());
+        /// ```
+        pub struct SecondaryAddress(String);
+        faker_impl_from_templates! {
+            SecondaryAddress;
+
+            "Flat {}", NonZeroAsciiDigit;
+            "Flat {}{}", NonZeroAsciiDigit, AsciiDigit;
+        }
+
+        /// Generates a first-level administrative division (i.e. a county).
+        ///
+        /// Counties are drawn from the ceremonial counties of England, the
+        /// preserved counties of Wales, and the counties of Northern Ireland.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::Division;
+        /// assert_eq!("West Midlands", rng.gen::<Division>().to_string());
+        /// ```
+        pub struct Division(&'static str);
+        faker_impl_from_file!(Division, "data/en_gb/divisions");
+
+        struct PostcodeArea(&'static str);
+        faker_impl_from_file!(PostcodeArea, "data/en_gb/postcode_areas");
+
+        // The letters allowed at the end of a district such as "W1A" or "EC1A",
+        // depending on whether the area has one letter or two.
+        const DISTRICT_LETTERS_AFTER_ONE: &[u8] = b"ABCDEFGHJKPSTUW";
+        const DISTRICT_LETTERS_AFTER_TWO: &[u8] = b"ABEHMNPRVWXY";
+
+        // The letters allowed in the unit at the end of a postcode.
+        const UNIT_LETTERS: &[u8] = b"ABDEFGHJLNPQRSTUWXYZ";
+
+        /// Generates a postcode, such as `SW1A 1AA`.
+        ///
+        /// A postcode is made up of an outward code and an inward code,
+        /// separated by a space. The outward code is one of the real postcode
+        /// areas (e.g. `SW`), followed by a district, which is a number that's
+        /// sometimes followed by a letter (e.g. `1A`). The inward code is a
+        /// digit followed by two letters. The letters in each position are
+        /// limited to those Royal Mail allows there, but no guarantee is made
+        /// that the postcode is actually in use.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::PostalCode;
+        /// assert_eq!("RM15 5BX", rng.gen::<PostalCode>().to_string());
+        /// # for _ in 0..1000 {
+        /// #     let postcode = rng.gen::<PostalCode>().to_string();
+        /// #     let (outward, inward) = postcode.split_once(' ').unwrap();
+        /// #     let area_len = outward.chars().take_while(|c| c.is_ascii_uppercase()).count();
+        /// #     assert!(area_len == 1 || area_len == 2, "{}", postcode);
+        /// #     assert!((2..=4).contains(&outward.len()), "{}", postcode);
+        /// #     assert!(outward[area_len..].starts_with(|c: char| c.is_ascii_digit()), "{}", postcode);
+        /// #     let inward = inward.as_bytes();
+        /// #     assert_eq!(3, inward.len(), "{}", postcode);
+        /// #     assert!(inward[0].is_ascii_digit(), "{}", postcode);
+        /// #     assert!(inward[1..].iter().all(|c| b"ABDEFGHJLNPQRSTUWXYZ".contains(c)), "{}", postcode);
+        /// # }
+        /// ```
+        pub struct PostalCode(String);
+
+        impl Distribution<PostalCode> for Standard {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PostalCode {
+                let area = rng.gen::<PostcodeArea>();
+                let district = if rng.gen_ratio(1, 10) {
+                    let letters = if area.len() == 1 {
+                        DISTRICT_LETTERS_AFTER_ONE
+                    } else {
+                        DISTRICT_LETTERS_AFTER_TWO
+                    };
+
+                    let letter = *letters.choose(rng).unwrap() as char;
+                    format!("{}{}", rng.gen_range(1..=9), letter)
+                } else {
+                    rng.gen_range(1..=29).to_string()
+                };
+
+                let sector = rng.gen_range(0..=9);
+                let unit: String = (0..2)
+                    .map(|_| *UNIT_LETTERS.choose(rng).unwrap() as char)
+                    .collect();
+
+                PostalCode(format!("{}{} {}{}", area, district, sector, unit))
+            }
+        }
+
+        __faker_impl_newtype!([] PostalCode);
+
+        /// Generates a full postal address.
+        ///
+        /// As Royal Mail recommends, the post town is in capital letters.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::addresses::Address;
+        /// assert_eq!("Ms Charlie Barker\nFlat 4\n685 Oak Row\nDUNDEE\nN20 4HL\n", rng.gen::<Address>().to_string());
+        /// ```
+        pub struct Address(String);
+        faker_impl_from_templates! {
+            Address;
+
+            "{}\n{}\n{}\n{}\n", FullName, StreetAddress, ToUppercase<CityName>, PostalCode;
+            "{}\n{}\n{}\n{}\n{}\n", FullName, SecondaryAddress, StreetAddress, ToUppercase<CityName>, PostalCode;
+        }
+    }
+
+    /// Generators for company names.
+    pub mod company {
+        use super::names::LastName;
+
+        struct CompanySuffix(&'static str);
+        faker_impl_from_file!(CompanySuffix, "data/en_gb/company_suffixes");
+
+        /// Generates a company name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::company::CompanyName;
+        /// assert_eq!("Mitchell & Collins plc", rng.gen::<CompanyName>().to_string());
+        /// ```
+        pub struct CompanyName(String);
+        faker_impl_from_templates! {
+            CompanyName;
+
+            "{} {}", LastName, CompanySuffix;
+            "{} & {} {}", LastName, LastName, CompanySuffix;
+        }
+    }
+
+    /// Generators for internet domain names, usernames, and emails.
+    pub mod internet {
+        use super::names::{FirstName, LastName};
+        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+
+        struct DomainWord(String);
+        faker_impl_from_templates! {
+            DomainWord;
+
+            "{}", ToAsciiLowercase<LastName>;
+        }
+
+        struct DomainTLD(&'static str);
+        faker_impl_from_file!(DomainTLD, "data/en_gb/domain_tlds");
+
+        /// Generates a domain name.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::internet::Domain;
+        /// assert_eq!("barker.net", rng.gen::<Domain>().to_string());
+        /// ```
+        pub struct Domain(String);
+        faker_impl_from_templates! {
+            Domain;
+
+            "{}.{}", DomainWord, DomainTLD;
+        }
+
+        /// Generates a username.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::internet::Username;
+        /// assert_eq!("ohall48", rng.gen::<Username>().to_string());
+        /// ```
+        pub struct Username(String);
+        faker_impl_from_templates! {
+            Username;
+
+            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
+            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
+            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
+            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+        }
+
+        /// Generates an email.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::internet::Email;
+        /// assert_eq!("ohall48@barnes.com", rng.gen::<Email>().to_string());
+        /// ```
+        pub struct Email(String);
+        faker_impl_from_templates! {
+            Email;
+
+            "{}@{}", Username, Domain;
+        }
+    }
+
+    /// Generators for phone numbers.
+    pub mod phones {
+        use crate::util::AsciiDigit;
+
+        /// Generates a phone number, formatted as `0NNNN NNNNNN`.
+        ///
+        /// The number is either a geographic number (starting with `01`) or a
+        /// mobile number (starting with `07`).
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::en_gb::phones::PhoneNumber;
+        /// assert_eq!("07755 489960", rng.gen::<PhoneNumber>().to_string());
+        /// ```
+        pub struct PhoneNumber(String);
+        faker_impl_from_templates! {
+            PhoneNumber;
+
+            "01{}{}{} {}{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+            "07{}{}{} {}{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        }
+    }
+}
+
+/// Generators for network addresses, which aren't specific to any locale.
+pub mod net {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+    use std::net::Ipv6Addr;
+
+    /// Generates an IPv4 address, in dotted-decimal notation.
+    ///
+    /// Each of the four octets is equally likely to be any value from 0 to 255.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::net::IpV4Address;
+    /// assert_eq!("108.103.55.95", rng.gen::<IpV4Address>().to_string());
+    /// ```
+    pub struct IpV4Address(String);
+
+    impl Distribution<IpV4Address> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IpV4Address {
+            let [a, b, c, d] = rng.gen::<[u8; 4]>();
+            IpV4Address(format!("{}.{}.{}.{}", a, b, c, d))
+        }
+    }
+
+    __faker_impl_newtype!([] IpV4Address);
+
+    /// Generates an IPv6 address, in fully-expanded notation.
+    ///
+    /// The address is made up of eight groups of four lowercase hexadecimal
+    /// digits, separated by colons. Leading zeros are never omitted.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::net::IpV6Address;
+    /// assert_eq!("3b6c:f767:c037:a55f:a6e6:0d32:887c:60a4", rng.gen::<IpV6Address>().to_string());
+    /// ```
+    pub struct IpV6Address(String);
+
+    impl Distribution<IpV6Address> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IpV6Address {
+            let groups: Vec<_> = rng
+                .gen::<[u16; 8]>()
+                .iter()
+                .map(|group| format!("{:04x}", group))
+                .collect();
+
+            IpV6Address(groups.join(":"))
+        }
+    }
+
+    __faker_impl_newtype!([] IpV6Address);
+
+    /// Generates an IPv6 address, in compressed notation.
+    ///
+    /// The address is formatted as recommended by RFC 5952: leading zeros are
+    /// omitted, and the longest run of zero groups is replaced with `::`.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::net::CompressedIpV6Address;
+    /// assert_eq!("3b6c:f767:c037:a55f:a6e6:d32:887c:60a4", rng.gen::<CompressedIpV6Address>().to_string());
+    /// ```
+    pub struct CompressedIpV6Address(String);
+
+    impl Distribution<CompressedIpV6Address> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CompressedIpV6Address {
+            let [a, b, c, d, e, f, g, h] = rng.gen::<[u16; 8]>();
+            CompressedIpV6Address(Ipv6Addr::new(a, b, c, d, e, f, g, h).to_string())
+        }
+    }
+
+    __faker_impl_newtype!([] CompressedIpV6Address);
+
+    /// Generates a MAC address.
+    ///
+    /// The address is made up of six pairs of lowercase hexadecimal digits,
+    /// separated by `SEP`, which defaults to a colon.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::net::MacAddress;
+    /// assert_eq!("6c:67:37:5f:e6:32", rng.gen::<MacAddress>().to_string());
+    /// assert_eq!("7c-a4-79-67-bd-cb", rng.gen::<MacAddress<'-'>>().to_string());
+    /// ```
+    pub struct MacAddress<const SEP: char = ':'>(String);
+
+    impl<const SEP: char> Distribution<MacAddress<SEP>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MacAddress<SEP> {
+            let octets: Vec<_> = rng
+                .gen::<[u8; 6]>()
+                .iter()
+                .map(|octet| format!("{:02x}", octet))
+                .collect();
+
+            MacAddress(octets.join(&SEP.to_string()))
+        }
+    }
+
+    __faker_impl_newtype!([const SEP: char] MacAddress<SEP>);
 }
 
-/// Utility generators that can be used as building blocks for larger
-/// generators.
-pub mod util {
-    /// Generates an ASCII decimal digit (0-9).
+/// Generators for calendar dates and times, which aren't specific to any
+/// locale.
+pub mod dates {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    /// Converts a year into the number of days from 1970-01-01 to January 1st
+    /// of that year, in the proleptic Gregorian calendar.
+    ///
+    /// This is Howard Hinnant's `days_from_civil` algorithm, specialized to the
+    /// first day of the year.
+    fn days_from_year(year: i64) -> i64 {
+        let y = year - 1;
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let doe = 365 * yoe + yoe / 4 - yoe / 100 + 306;
+
+        era * 146097 + doe - 719468
+    }
+
+    /// Converts a number of days since 1970-01-01 into a (year, month, day)
+    /// triple in the proleptic Gregorian calendar.
+    ///
+    /// This is Howard Hinnant's `civil_from_days` algorithm.
+    fn civil_from_days(days: i64) -> (i64, u32, u32) {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        (year, month, day)
+    }
+
+    /// Samples a date in `YYYY-MM-DD` format, from January 1st of `start_year`
+    /// up to, but not including, January 1st of `end_year`.
+    fn sample_date<R: Rng + ?Sized>(rng: &mut R, start_year: i64, end_year: i64) -> String {
+        let days = rng.gen_range(days_from_year(start_year)..days_from_year(end_year));
+        let (year, month, day) = civil_from_days(days);
+
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+
+    /// Samples a time of day in `HH:MM:SS` format.
+    fn sample_time<R: Rng + ?Sized>(rng: &mut R) -> String {
+        let hour = rng.gen_range(0..24);
+        let minute = rng.gen_range(0..60);
+        let second = rng.gen_range(0..60);
+
+        format!("{:02}:{:02}:{:02}", hour, minute, second)
+    }
+
+    /// Generates a calendar date, in ISO 8601 (`YYYY-MM-DD`) format.
+    ///
+    /// Dates are chosen uniformly from 1970-01-01 up to, but not including,
+    /// 2038-01-01. Month lengths and leap years are taken into account, so
+    /// every generated date really exists.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::dates::IsoDate;
+    /// assert_eq!("2018-03-21", rng.gen::<IsoDate>().to_string());
+    /// assert_eq!("2001-09-07", rng.gen::<IsoDate>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     let date = rng.gen::<IsoDate>().to_string();
+    /// #     let parts: Vec<u32> = date.split('-').map(|p| p.parse().unwrap()).collect();
+    /// #     let (year, month, day) = (parts[0], parts[1], parts[2]);
+    /// #     let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    /// #     let days_in_month = match month {
+    /// #         2 if leap => 29,
+    /// #         2 => 28,
+    /// #         4 | 6 | 9 | 11 => 30,
+    /// #         1..=12 => 31,
+    /// #         _ => panic!("bad month in {}", date),
+    /// #     };
+    /// #     assert_eq!(10, date.len());
+    /// #     assert!((1970..2038).contains(&year), "bad year in {}", date);
+    /// #     assert!((1..=days_in_month).contains(&day), "bad day in {}", date);
+    /// # }
+    /// ```
+    pub struct IsoDate(String);
+
+    impl Distribution<IsoDate> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> IsoDate {
+            IsoDate(sample_date(rng, 1970, 2038))
+        }
+    }
+
+    __faker_impl_newtype!([] IsoDate);
+
+    /// Generates a time of day on a 24-hour clock, in `HH:MM:SS` format.
+    ///
+    /// Hours range from 00 to 23, and minutes and seconds from 00 to 59.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::dates::Time24;
+    /// assert_eq!("15:42:43", rng.gen::<Time24>().to_string());
+    /// assert_eq!("11:30:33", rng.gen::<Time24>().to_string());
+    /// ```
+    pub struct Time24(String);
+
+    impl Distribution<Time24> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time24 {
+            Time24(sample_time(rng))
+        }
+    }
+
+    __faker_impl_newtype!([] Time24);
+
+    /// Generates a time of day on a 12-hour clock, in `hh:mm AM` or `hh:mm PM`
+    /// format.
+    ///
+    /// Hours range from 01 to 12, and minutes from 00 to 59.
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::util::AsciiDigit;
-    /// assert_eq!("7", rng.gen::<AsciiDigit>().to_string());
+    /// use faker_rand::dates::Time12;
+    /// assert_eq!("09:43 PM", rng.gen::<Time12>().to_string());
+    /// assert_eq!("07:33 PM", rng.gen::<Time12>().to_string());
     /// ```
-    pub struct AsciiDigit(String);
-    faker_impl_from_file!(AsciiDigit, "data/ascii_digit");
+    pub struct Time12(String);
 
-    /// Generates an ASCII lowercase letter (a-z).
+    impl Distribution<Time12> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time12 {
+            let hour = rng.gen_range(1..=12);
+            let minute = rng.gen_range(0..60);
+            let meridiem = if rng.gen() { "AM" } else { "PM" };
+
+            Time12(format!("{:02}:{:02} {}", hour, minute, meridiem))
+        }
+    }
+
+    __faker_impl_newtype!([] Time12);
+
+    /// Generates an RFC 3339 timestamp in UTC, such as `2021-07-15T13:42:09Z`.
+    ///
+    /// The date part is chosen uniformly from January 1st of `START_YEAR` up
+    /// to, but not including, January 1st of `END_YEAR`, in the same way as
+    /// [`IsoDate`]. The time part is chosen in the same way as [`Time24`].
+    /// `START_YEAR` and `END_YEAR` default to 1970 and 2038, and must be
+    /// between 0 and 10000, with `START_YEAR` less than `END_YEAR`. Other
+    /// years are rejected at compile time.
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::util::AsciiLowercase;
-    /// assert_eq!("s", rng.gen::<AsciiLowercase>().to_string());
+    /// use faker_rand::dates::Rfc3339;
+    /// assert_eq!("2018-03-21T17:30:33Z", rng.gen::<Rfc3339>().to_string());
+    /// assert_eq!("2021-12-14T21:34:24Z", rng.gen::<Rfc3339<2020, 2030>>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     for timestamp in [
+    /// #         rng.gen::<Rfc3339>().to_string(),
+    /// #         rng.gen::<Rfc3339<0, 10000>>().to_string(),
+    /// #     ] {
+    /// #         let parsed = chrono::DateTime::parse_from_rfc3339(&timestamp).unwrap();
+    /// #         assert_eq!(
+    /// #             timestamp,
+    /// #             parsed.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    /// #         );
+    /// #     }
+    /// # }
     /// ```
-    pub struct AsciiLowercase(String);
-    faker_impl_from_file!(AsciiLowercase, "data/ascii_lowercase");
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::dates::Rfc3339;
+    /// rng.gen::<Rfc3339<-5, 2000>>();
+    /// ```
+    pub struct Rfc3339<const START_YEAR: i64 = 1970, const END_YEAR: i64 = 2038>(String);
+
+    impl<const START_YEAR: i64, const END_YEAR: i64> Rfc3339<START_YEAR, END_YEAR> {
+        const ASSERT_VALID: () = {
+            assert!(
+                0 <= START_YEAR && END_YEAR <= 10000,
+                "RFC 3339 years must be between 0 and 10000"
+            );
+            assert!(
+                START_YEAR < END_YEAR,
+                "START_YEAR must be less than END_YEAR"
+            );
+        };
+    }
+
+    impl<const START_YEAR: i64, const END_YEAR: i64> Distribution<Rfc3339<START_YEAR, END_YEAR>>
+        for Standard
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rfc3339<START_YEAR, END_YEAR> {
+            let () = Rfc3339::<START_YEAR, END_YEAR>::ASSERT_VALID;
+
+            let date = sample_date(rng, START_YEAR, END_YEAR);
+            let time = sample_time(rng);
+
+            Rfc3339(format!("{}T{}Z", date, time))
+        }
+    }
+
+    __faker_impl_newtype!([const START_YEAR: i64, const END_YEAR: i64] Rfc3339<START_YEAR, END_YEAR>);
+}
+
+/// Generators for payment data, which aren't specific to any locale.
+///
+/// None of the generated data corresponds to real payment instruments. It is
+/// only meant to look realistic, and to pass basic client-side validation.
+///
+/// To generate a whole card, including its holder's name, see
+/// [`en_us::payments::CreditCard`].
+pub mod payments {
+    /// Generates the name of a card network, such as "Visa" or "American
+    /// Express".
+    ///
+    /// The names are chosen independently of [`CreditCardNumber`]. To generate
+    /// a card number along with the name of its network, use
+    /// [`CreditCardNumberWithScheme`] instead.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::payments::CardScheme;
+    /// assert_eq!("Visa", rng.gen::<CardScheme>().to_string());
+    /// assert_eq!("American Express", rng.gen::<CardScheme>().to_string());
+    /// ```
+    pub struct CardScheme(&'static str);
+    faker_impl_from_file!(CardScheme, "data/card_schemes");
 
     use rand::distributions::{Distribution, Standard};
     use rand::Rng;
-    use std::fmt;
-    use std::marker::PhantomData;
 
-    /// Wraps a string generator so that its output is all ASCII lowercase
-    /// letters (a-z).
+    /// A card network, which determines the prefix and length of card numbers.
+    #[derive(Clone, Copy)]
+    pub(crate) enum Scheme {
+        Visa,
+        Mastercard,
+        Amex,
+        Discover,
+    }
+
+    impl Distribution<Scheme> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Scheme {
+            match rng.gen_range(0..4) {
+                0 => Scheme::Visa,
+                1 => Scheme::Mastercard,
+                2 => Scheme::Amex,
+                _ => Scheme::Discover,
+            }
+        }
+    }
+
+    impl Scheme {
+        /// The name of this scheme, as it appears in `data/card_schemes`.
+        fn name(self) -> &'static str {
+            match self {
+                Scheme::Visa => "Visa",
+                Scheme::Mastercard => "Mastercard",
+                Scheme::Amex => "American Express",
+                Scheme::Discover => "Discover",
+            }
+        }
+
+        /// Samples a card number for this scheme, as a string of digits with a
+        /// valid Luhn check digit.
+        fn sample_number<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+            let (prefix, len) = match self {
+                Scheme::Visa => ("4".to_string(), 16),
+                Scheme::Mastercard => {
+                    let prefix = if rng.gen() {
+                        rng.gen_range(51..=55)
+                    } else {
+                        rng.gen_range(2221..=2720)
+                    };
+
+                    (prefix.to_string(), 16)
+                }
+                Scheme::Amex => ((if rng.gen() { "34" } else { "37" }).to_string(), 15),
+                Scheme::Discover => ("6011".to_string(), 16),
+            };
+
+            let mut digits = prefix;
+            while digits.len() < len - 1 {
+                digits.push((b'0' + rng.gen_range(0..10)) as char);
+            }
+
+            digits.push(luhn_check_digit(&digits));
+            digits
+        }
+
+        /// Samples a card number for this scheme, split into the space-separated
+        /// groups it's conventionally displayed in.
+        pub(crate) fn sample_grouped_number<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+            let digits = self.sample_number(rng);
+
+            let mut groups = Vec::new();
+            let mut rest = &digits[..];
+            for &size in self.groups() {
+                let (group, tail) = rest.split_at(size);
+                groups.push(group);
+                rest = tail;
+            }
+
+            groups.join(" ")
+        }
+
+        /// The number of digits in this scheme's card security code.
+        pub(crate) fn cvv_len(self) -> usize {
+            match self {
+                Scheme::Amex => 4,
+                Scheme::Visa | Scheme::Mastercard | Scheme::Discover => 3,
+            }
+        }
+
+        /// The sizes of the space-separated groups card numbers of this scheme
+        /// are conventionally displayed in.
+        fn groups(self) -> &'static [usize] {
+            match self {
+                Scheme::Visa | Scheme::Mastercard | Scheme::Discover => &[4, 4, 4, 4],
+                Scheme::Amex => &[4, 6, 5],
+            }
+        }
+    }
+
+    /// Computes the digit that, once appended to `digits`, makes the result
+    /// pass the Luhn checksum.
+    fn luhn_check_digit(digits: &str) -> char {
+        let sum: u32 = digits
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(i, b)| {
+                let d = (b - b'0') as u32;
+                if i % 2 == 0 {
+                    let d = d * 2;
+                    if d > 9 {
+                        d - 9
+                    } else {
+                        d
+                    }
+                } else {
+                    d
+                }
+            })
+            .sum();
+
+        (b'0' + ((10 - sum % 10) % 10) as u8) as char
+    }
+
+    /// Generates a credit card number with a valid Luhn check digit.
     ///
-    /// Any character that can't be lowercased to ASCII lowercase is stripped
-    /// from the output.
+    /// The card network is chosen with equal likelihood from Visa (16 digits,
+    /// starting with 4), Mastercard (16 digits, starting with 51 through 55 or
+    /// 2221 through 2720), American Express (15 digits, starting with 34 or
+    /// 37), and Discover (16 digits, starting with 6011). The number is split
+    /// into space-separated groups, as it would be printed on the card: 4-6-5
+    /// for American Express, and 4-4-4-4 for the others.
+    ///
+    /// To get the digits without spaces, wrap this generator in
+    /// [`StripWhitespace`](crate::util::StripWhitespace).
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// // FirstName generates strings whose first letter is capitalized ...
-    /// use faker_rand::en_us::names::FirstName;
-    /// assert_eq!("Melvin", rng.gen::<FirstName>().to_string());
+    /// use faker_rand::payments::CreditCardNumber;
+    /// assert_eq!("3450 185548 89481", rng.gen::<CreditCardNumber>().to_string());
+    /// assert_eq!("6011 4563 0756 0191", rng.gen::<CreditCardNumber>().to_string());
     ///
-    /// // ... But with ToAsciiLowercase, it's lowercased.
-    /// use faker_rand::util::ToAsciiLowercase;
-    /// assert_eq!("jamey", rng.gen::<ToAsciiLowercase<FirstName>>().to_string());
+    /// use faker_rand::util::StripWhitespace;
+    /// assert_eq!("348588715770959", rng.gen::<StripWhitespace<CreditCardNumber>>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     let number = rng.gen::<StripWhitespace<CreditCardNumber>>().to_string();
+    /// #     assert!(number.bytes().all(|b| b.is_ascii_digit()));
+    /// #     assert!(
+    /// #         (number.starts_with('4') && number.len() == 16)
+    /// #             || (("51".."56").contains(&&number[..2]) && number.len() == 16)
+    /// #             || (("2221".."2721").contains(&&number[..4]) && number.len() == 16)
+    /// #             || ((number.starts_with("34") || number.starts_with("37")) && number.len() == 15)
+    /// #             || (number.starts_with("6011") && number.len() == 16),
+    /// #         "bad prefix or length: {}",
+    /// #         number
+    /// #     );
+    /// #     let sum: u32 = number
+    /// #         .bytes()
+    /// #         .rev()
+    /// #         .enumerate()
+    /// #         .map(|(i, b)| {
+    /// #             let d = (b - b'0') as u32;
+    /// #             if i % 2 == 1 { (d * 2) / 10 + (d * 2) % 10 } else { d }
+    /// #         })
+    /// #         .sum();
+    /// #     assert_eq!(0, sum % 10, "bad checksum: {}", number);
+    /// # }
     /// ```
-    pub struct ToAsciiLowercase<T>(String, PhantomData<T>);
+    pub struct CreditCardNumber(String);
 
-    impl<T: ToString> Distribution<ToAsciiLowercase<T>> for Standard
-    where
-        Standard: Distribution<T>,
-    {
-        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ToAsciiLowercase<T> {
-            let mut s = deunicode::deunicode(&rng.gen::<T>().to_string()).to_lowercase();
-            s.retain(|c| c.is_ascii_lowercase());
+    impl Distribution<CreditCardNumber> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CreditCardNumber {
+            let scheme: Scheme = rng.gen();
+            CreditCardNumber(scheme.sample_grouped_number(rng))
+        }
+    }
 
-            ToAsciiLowercase(s, std::marker::PhantomData)
+    __faker_impl_newtype!([] CreditCardNumber);
+
+    /// Defines a generator for the card numbers of a single [`Scheme`], which
+    /// are generated in the same way as [`CreditCardNumber`] generates them.
+    macro_rules! scheme_card_number {
+        ($(#[$attr: meta])* $name: ident, $scheme: expr) => {
+            $(#[$attr])*
+            pub struct $name(String);
+
+            impl Distribution<$name> for Standard {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                    $name($scheme.sample_grouped_number(rng))
+                }
+            }
+
+            __faker_impl_newtype!([] $name);
+        };
+    }
+
+    scheme_card_number! {
+        /// Generates a Visa card number with a valid Luhn check digit,
+        /// such as "4111 1111 1111 1111".
+        ///
+        /// The number is generated in the same way as [`CreditCardNumber`],
+        /// except that it's always from the Visa network.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::payments::VisaNumber;
+        /// assert_eq!("4677 4565 0185 5488", rng.gen::<VisaNumber>().to_string());
+        /// ```
+        VisaNumber,
+        Scheme::Visa
+    }
+
+    scheme_card_number! {
+        /// Generates a Mastercard card number with a valid Luhn check digit,
+        /// such as "5500 0000 0000 0004".
+        ///
+        /// The number is generated in the same way as [`CreditCardNumber`],
+        /// except that it's always from the Mastercard network.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::payments::MastercardNumber;
+        /// assert_eq!("5474 5650 1855 4889", rng.gen::<MastercardNumber>().to_string());
+        /// ```
+        MastercardNumber,
+        Scheme::Mastercard
+    }
+
+    scheme_card_number! {
+        /// Generates an American Express card number with a valid Luhn check
+        /// digit, such as "3782 822463 10005".
+        ///
+        /// The number is generated in the same way as [`CreditCardNumber`],
+        /// except that it's always from the American Express network.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::payments::AmexNumber;
+        /// assert_eq!("3477 456501 85541", rng.gen::<AmexNumber>().to_string());
+        /// ```
+        AmexNumber,
+        Scheme::Amex
+    }
+
+    scheme_card_number! {
+        /// Generates a Discover card number with a valid Luhn check digit,
+        /// such as "6011 1111 1111 1117".
+        ///
+        /// The number is generated in the same way as [`CreditCardNumber`],
+        /// except that it's always from the Discover network.
+        ///
+        /// ```
+        /// use rand::{Rng, SeedableRng};
+        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        ///
+        /// use faker_rand::payments::DiscoverNumber;
+        /// assert_eq!("6011 6774 5650 1856", rng.gen::<DiscoverNumber>().to_string());
+        /// ```
+        DiscoverNumber,
+        Scheme::Discover
+    }
+
+    /// Generates the name of a card network, followed by a space and a credit
+    /// card number from that network.
+    ///
+    /// The network and number are generated in the same way as
+    /// [`CardScheme`] and [`CreditCardNumber`], except that the number's
+    /// prefix and length always match the network's name.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::payments::CreditCardNumberWithScheme;
+    /// assert_eq!("American Express 3450 185548 89481", rng.gen::<CreditCardNumberWithScheme>().to_string());
+    /// assert_eq!("Discover 6011 4563 0756 0191", rng.gen::<CreditCardNumberWithScheme>().to_string());
+    /// ```
+    pub struct CreditCardNumberWithScheme(String);
+
+    impl Distribution<CreditCardNumberWithScheme> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CreditCardNumberWithScheme {
+            let scheme: Scheme = rng.gen();
+            let number = scheme.sample_grouped_number(rng);
+
+            CreditCardNumberWithScheme(format!("{} {}", scheme.name(), number))
         }
     }
 
-    impl<T: ToString> fmt::Display for ToAsciiLowercase<T> {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}", self.0)
+    __faker_impl_newtype!([] CreditCardNumberWithScheme);
+
+    /// Samples a card expiry date in `MM/YY` format, between 1 and 5 years
+    /// after `from_year`.
+    pub(crate) fn sample_expiry<R: Rng + ?Sized>(rng: &mut R, from_year: i64) -> String {
+        let month = rng.gen_range(1..=12);
+        let year = rng.gen_range(from_year + 1..=from_year + 5);
+
+        format!("{:02}/{:02}", month, year.rem_euclid(100))
+    }
+
+    /// Generates a card expiry date in `MM/YY` format, such as "08/27".
+    ///
+    /// The month is between 01 and 12, and the year is between 1 and 5 years
+    /// after `FROM_YEAR`, which defaults to 2026. Because the year is fixed
+    /// rather than taken from the system clock, generated values don't change
+    /// over time.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::payments::CardExpiry;
+    /// assert_eq!("09/29", rng.gen::<CardExpiry>().to_string());
+    /// assert_eq!("07/33", rng.gen::<CardExpiry<2030>>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<CardExpiry>().to_string();
+    /// #     let (month, year) = s.split_once('/').unwrap();
+    /// #     assert!(("01"..="12").contains(&month) && month.len() == 2, "{}", s);
+    /// #     assert!(("27"..="31").contains(&year) && year.len() == 2, "{}", s);
+    /// # }
+    /// ```
+    pub struct CardExpiry<const FROM_YEAR: i64 = 2026>(String);
+
+    impl<const FROM_YEAR: i64> Distribution<CardExpiry<FROM_YEAR>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CardExpiry<FROM_YEAR> {
+            CardExpiry(sample_expiry(rng, FROM_YEAR))
         }
     }
 
-    /// Wraps a string generator so that the first letter of its output is
-    /// capitalized.
+    __faker_impl_newtype!([const FROM_YEAR: i64] CardExpiry<FROM_YEAR>);
+}
+
+/// Generators for financial data, which aren't specific to any locale.
+pub mod finance {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    /// Generates an ISO 4217 currency code, such as "USD" or "EUR".
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// // Word generates strings that are all lowercase ...
-    /// use faker_rand::lorem::Word;
-    /// assert_eq!("impedit", rng.gen::<Word>().to_string());
+    /// use faker_rand::finance::CurrencyCode;
+    /// assert_eq!("NGN", rng.gen::<CurrencyCode>().to_string());
+    /// assert_eq!("AUD", rng.gen::<CurrencyCode>().to_string());
+    /// ```
+    pub struct CurrencyCode(String);
+    faker_impl_from_csv!(CurrencyCode, "data/currencies.csv", "{0}");
+
+    /// Generates a currency symbol, such as "$" or "€".
+    ///
+    /// The symbols are chosen independently of [`CurrencyCode`]. To generate a
+    /// code along with its symbol, use [`Currency`] instead.
     ///
-    /// // ... But with CapitalizeFirstLetter, the first letter is capitalized.
-    /// use faker_rand::util::CapitalizeFirstLetter;
-    /// assert_eq!("Totam", rng.gen::<CapitalizeFirstLetter<Word>>().to_string());
     /// ```
-    pub struct CapitalizeFirstLetter<T>(String, PhantomData<T>);
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::finance::CurrencySymbol;
+    /// assert_eq!("₦", rng.gen::<CurrencySymbol>().to_string());
+    /// assert_eq!("A$", rng.gen::<CurrencySymbol>().to_string());
+    /// ```
+    pub struct CurrencySymbol(String);
+    faker_impl_from_csv!(CurrencySymbol, "data/currencies.csv", "{1}");
 
-    impl<T: ToString> Distribution<CapitalizeFirstLetter<T>> for Standard
-    where
-        Standard: Distribution<T>,
-    {
-        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CapitalizeFirstLetter<T> {
-            let s = rng.gen::<T>().to_string();
-            let mut c = s.chars();
+    /// Generates an ISO 4217 currency code followed by its symbol in
+    /// parentheses, such as "USD ($)".
+    ///
+    /// The currencies are the same as those of [`CurrencyCode`] and
+    /// [`CurrencySymbol`], but the code and symbol always belong to the same
+    /// currency.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::finance::Currency;
+    /// assert_eq!("NGN (₦)", rng.gen::<Currency>().to_string());
+    /// assert_eq!("AUD (A$)", rng.gen::<Currency>().to_string());
+    /// ```
+    pub struct Currency(String);
+    faker_impl_from_csv!(Currency, "data/currencies.csv", "{0} ({1})");
 
-            CapitalizeFirstLetter(
-                c.next().unwrap().to_uppercase().chain(c).collect(),
-                std::marker::PhantomData,
-            )
+    /// Generates a monetary amount between `MIN` and `MAX`, inclusive, with
+    /// exactly two decimal places, such as "49.99".
+    ///
+    /// Each amount in the range, in steps of 0.01, is equally likely. `MIN` and
+    /// `MAX` default to 0 and 1000. `MIN` must be no greater than `MAX`, and
+    /// `MAX` no greater than `u64::MAX / 100`, which is checked at compile
+    /// time. The output has no currency symbol and no thousands separators, so
+    /// it can be combined with [`CurrencyCode`] or [`CurrencySymbol`] in
+    /// templates.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::finance::Price;
+    /// assert_eq!("709.08", rng.gen::<Price>().to_string());
+    /// assert_eq!("2863.69", rng.gen::<Price<1000, 5000>>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     for price in [rng.gen::<Price>().to_string(), rng.gen::<Price<5, 10>>().to_string()] {
+    /// #         let (units, cents) = price.split_once('.').unwrap();
+    /// #         assert!(!units.is_empty() && units.bytes().all(|b| b.is_ascii_digit()));
+    /// #         assert!(cents.len() == 2 && cents.bytes().all(|b| b.is_ascii_digit()));
+    /// #     }
+    /// #     let price: f64 = rng.gen::<Price<5, 10>>().to_string().parse().unwrap();
+    /// #     assert!((5.0..=10.0).contains(&price));
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::finance::Price;
+    /// rng.gen::<Price<0, { u64::MAX }>>();
+    /// ```
+    pub struct Price<const MIN: u64 = 0, const MAX: u64 = 1000>(String);
+
+    impl<const MIN: u64, const MAX: u64> Price<MIN, MAX> {
+        const ASSERT_VALID: () = {
+            assert!(MIN <= MAX, "MIN must be no greater than MAX");
+            assert!(
+                MAX <= u64::MAX / 100,
+                "MAX is too large to be counted in cents"
+            );
+        };
+    }
+
+    impl<const MIN: u64, const MAX: u64> Distribution<Price<MIN, MAX>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Price<MIN, MAX> {
+            let () = Price::<MIN, MAX>::ASSERT_VALID;
+
+            let cents = rng.gen_range(MIN * 100..=MAX * 100);
+            Price(format!("{}.{:02}", cents / 100, cents % 100))
         }
     }
 
-    impl<T: ToString> fmt::Display for CapitalizeFirstLetter<T> {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}", self.0)
+    __faker_impl_newtype!([const MIN: u64, const MAX: u64] Price<MIN, MAX>);
+}
+
+/// Generators for colors, which aren't specific to any locale.
+pub mod colors {
+    /// Generates the name of a color, such as "teal" or "salmon".
+    ///
+    /// The names are those of the CSS named colors, written in lowercase with
+    /// spaces between words.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::colors::ColorName;
+    /// assert_eq!("blanched almond", rng.gen::<ColorName>().to_string());
+    /// assert_eq!("lime green", rng.gen::<ColorName>().to_string());
+    /// ```
+    pub struct ColorName(&'static str);
+    faker_impl_from_file!(ColorName, "data/colors");
+
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    /// Generates a color in CSS hexadecimal notation, such as `#3b6cf7`.
+    ///
+    /// Each of the red, green, and blue channels is equally likely to be any
+    /// value from 0 to 255, so all 16,777,216 colors are equally likely. The
+    /// hexadecimal digits are lowercase.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::colors::HexColor;
+    /// assert_eq!("#6c6737", rng.gen::<HexColor>().to_string());
+    /// assert_eq!("#5fe632", rng.gen::<HexColor>().to_string());
+    /// # for _ in 0..1000 {
+    /// #     let color = rng.gen::<HexColor>().to_string();
+    /// #     assert_eq!(7, color.len());
+    /// #     assert!(color.starts_with('#'));
+    /// #     assert!(color[1..].bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)));
+    /// # }
+    /// ```
+    pub struct HexColor(String);
+
+    impl Distribution<HexColor> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HexColor {
+            let [r, g, b] = rng.gen::<[u8; 3]>();
+            HexColor(format!("#{:02x}{:02x}{:02x}", r, g, b))
         }
     }
+
+    __faker_impl_newtype!([] HexColor);
+
+    /// Generates a color in CSS functional notation, such as
+    /// `rgb(59, 108, 247)`.
+    ///
+    /// Each of the red, green, and blue channels is equally likely to be any
+    /// value from 0 to 255.
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::colors::RgbColor;
+    /// assert_eq!("rgb(108, 103, 55)", rng.gen::<RgbColor>().to_string());
+    /// assert_eq!("rgb(95, 230, 50)", rng.gen::<RgbColor>().to_string());
+    /// ```
+    pub struct RgbColor(String);
+
+    impl Distribution<RgbColor> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RgbColor {
+            let [r, g, b] = rng.gen::<[u8; 3]>();
+            RgbColor(format!("rgb({}, {}, {})", r, g, b))
+        }
+    }
+
+    __faker_impl_newtype!([] RgbColor);
 }
 
-/// Generators for "lorem ipsum" placeholder text.
-pub mod lorem {
-    use crate::util::CapitalizeFirstLetter;
+/// Generators for file names and paths, which aren't specific to any locale.
+pub mod files {
+    use crate::lorem::Word;
+    use crate::util::JoinRange;
 
-    /// Generates a lorem ipsum word.
+    /// Generates a file extension, without a leading period, such as "pdf".
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::lorem::Word;
-    /// assert_eq!("impedit", rng.gen::<Word>().to_string());
+    /// use faker_rand::files::FileExtension;
+    /// assert_eq!("mp3", rng.gen::<FileExtension>().to_string());
+    /// assert_eq!("gz", rng.gen::<FileExtension>().to_string());
     /// ```
-    pub struct Word(String);
-    faker_impl_from_file!(Word, "data/lorem_words");
-
-    struct FirstWord(String);
-    faker_impl_from_templates! {
-        FirstWord;
-
-        "{}", CapitalizeFirstLetter<Word>;
-    }
+    pub struct FileExtension(String);
+    faker_impl_from_csv!(FileExtension, "data/file_types.csv", "{0}");
 
-    /// Generates a lorem ipsum sentence.
+    /// Generates a MIME type, such as "application/pdf".
+    ///
+    /// The MIME types are those of the extensions that [`FileExtension`]
+    /// generates.
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::lorem::Sentence;
-    /// assert_eq!(
-    ///     "Cumque debitis unde eum recusandae aut.",
-    ///     rng.gen::<Sentence>().to_string()
-    /// );
+    /// use faker_rand::files::MimeType;
+    /// assert_eq!("audio/mpeg", rng.gen::<MimeType>().to_string());
+    /// assert_eq!("application/gzip", rng.gen::<MimeType>().to_string());
     /// ```
-    pub struct Sentence(String);
-    faker_impl_from_templates! {
-        Sentence;
-
-        "{} {} {}.", FirstWord, Word, Word;
-        "{} {} {} {}.", FirstWord, Word, Word, Word;
-        "{} {} {} {} {}.", FirstWord, Word, Word, Word, Word;
-        "{} {} {} {} {} {}.", FirstWord, Word, Word, Word, Word, Word;
-        "{} {} {} {} {} {} {}.", FirstWord, Word, Word, Word, Word, Word, Word;
-    }
+    pub struct MimeType(String);
+    faker_impl_from_csv!(MimeType, "data/file_types.csv", "{1}");
 
-    /// Generates a lorem ipsum paragraph.
+    /// Generates a file name, made of a lorem ipsum word and a
+    /// [`FileExtension`], such as "impedit.pdf".
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::lorem::Paragraph;
-    /// assert_eq!(
-    ///     "Debitis unde eum recusandae aut. Aut assumenda cupiditate aliquid voluptas facilis consectetur. Repellendus quae perspiciatis asperiores impedit. Voluptate dolorem in autem et. Consequatur iusto corrupti eum cupiditate.",
-    ///     rng.gen::<Paragraph>().to_string()
-    /// );
+    /// use faker_rand::files::FileName;
+    /// assert_eq!("cumque.csv", rng.gen::<FileName>().to_string());
+    /// assert_eq!("culpa.zip", rng.gen::<FileName>().to_string());
     /// ```
-    pub struct Paragraph(String);
+    pub struct FileName(String);
     faker_impl_from_templates! {
-        Paragraph;
+        FileName;
 
-        "{} {} {}", Sentence, Sentence, Sentence;
-        "{} {} {} {}", Sentence, Sentence, Sentence, Sentence;
-        "{} {} {} {} {}", Sentence, Sentence, Sentence, Sentence, Sentence;
+        "{}.{}", Word, FileExtension;
     }
 
-    /// Generates multiple lorem ipsum paragraphs.
+    /// Generates an absolute file path, such as "/totam/cumque/impedit.pdf".
+    ///
+    /// The path is made of between one and three directories, each a lorem
+    /// ipsum word, followed by a [`FileName`]. Path components are always
+    /// separated by `/`, whatever the current platform.
     ///
     /// ```
     /// use rand::{Rng, SeedableRng};
     /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
     ///
-    /// use faker_rand::lorem::Paragraphs;
-    /// assert_eq!(
-    ///     "Debitis unde eum recusandae aut. Aut assumenda cupiditate aliquid voluptas facilis consectetur. Repellendus quae perspiciatis asperiores impedit. Voluptate dolorem in autem et. Consequatur iusto corrupti eum cupiditate.\nDignissimos sit cupiditate vitae. Ex quidem odio quia nam. Doloribus reiciendis dignissimos in cum ad reprehenderit.\nEt error illum. Animi voluptatem quo temporibus velit consequatur. Ipsa corrupti cupiditate in et.\nSapiente molestiae sed. Ipsa voluptas rerum laborum. Sed natus et eum officiis ut. Ut voluptatem sint consequatur fuga explicabo asperiores. Aliquam vero quia cupiditate exercitationem blanditiis ea.\nMinima incidunt velit provident voluptate odio. Eius sequi unde voluptas qui. Possimus aut optio et. Consequuntur soluta aut dicta eos amet rerum. Eveniet corporis repudiandae aspernatur.\n",
-    ///     rng.gen::<Paragraphs>().to_string()
-    /// );
+    /// use faker_rand::files::FilePath;
+    /// assert_eq!("/debitis/unde/eum/ut.rs", rng.gen::<FilePath>().to_string());
+    /// assert_eq!("/in/doloribus.svg", rng.gen::<FilePath>().to_string());
     /// ```
-    pub struct Paragraphs(String);
+    pub struct FilePath(String);
     faker_impl_from_templates! {
-        Paragraphs;
+        FilePath;
 
-        "{}\n{}\n{}\n", Paragraph, Paragraph, Paragraph;
-        "{}\n{}\n{}\n{}\n", Paragraph, Paragraph, Paragraph, Paragraph;
-        "{}\n{}\n{}\n{}\n{}\n", Paragraph, Paragraph, Paragraph, Paragraph, Paragraph;
+        "/{}/{}", JoinRange<Word, 1, 3, '/'>, FileName;
     }
 }
 
-/// Localized generators for English as spoken in the United States (`en-US`).
-pub mod en_us {
-    /// Generators for the names of individuals (e.g., first, last, or full
-    /// names).
-    pub mod names {
-        /// Generates a first name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::names::FirstName;
-        /// assert_eq!("Melvin", rng.gen::<FirstName>().to_string());
-        /// ```
-        pub struct FirstName(String);
-        faker_impl_from_file!(FirstName, "data/en_us/first_names");
+/// Generators for geographic coordinates, which aren't specific to any locale.
+pub mod geo {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
 
-        /// Generates a last name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::names::LastName;
-        /// assert_eq!("Quitzon", rng.gen::<LastName>().to_string());
-        /// ```
-        pub struct LastName(String);
-        faker_impl_from_file!(LastName, "data/en_us/last_names");
+    /// Generates a latitude between -90 and 90 degrees, inclusive, with exactly
+    /// six decimal places, such as "42.358056".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::geo::Latitude;
+    /// assert_eq!("37.633575", rng.gen::<Latitude>().to_string());
+    /// assert_eq!("-6.134090", rng.gen::<Latitude>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<Latitude>().to_string();
+    /// #     assert_eq!(6, s.split_once('.').unwrap().1.len(), "{}", s);
+    /// #     assert!((-90.0..=90.0).contains(&s.parse::<f64>().unwrap()), "{}", s);
+    /// # }
+    /// ```
+    pub struct Latitude(String);
 
-        /// Generates a name prefix.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::names::NamePrefix;
-        /// assert_eq!("Miss", rng.gen::<NamePrefix>().to_string());
-        /// ```
-        pub struct NamePrefix(String);
-        faker_impl_from_file!(NamePrefix, "data/en_us/name_prefixes");
+    impl Distribution<Latitude> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Latitude {
+            Latitude(format!("{:.6}", rng.gen_range(-90.0..=90.0)))
+        }
+    }
 
-        /// Generates a name suffix.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::names::NameSuffix;
-        /// assert_eq!("IV", rng.gen::<NameSuffix>().to_string());
-        /// ```
-        pub struct NameSuffix(String);
-        faker_impl_from_file!(NameSuffix, "data/en_us/name_suffixes");
+    __faker_impl_newtype!([] Latitude);
 
-        /// Generates a full name, including possibly a prefix, suffix, or both.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::names::FullName;
-        /// assert_eq!("Cleta McClure III", rng.gen::<FullName>().to_string());
-        /// ```
-        pub struct FullName(String);
-        faker_impl_from_templates! {
-            FullName;
+    /// Generates a longitude between -180 and 180 degrees, inclusive, with
+    /// exactly six decimal places, such as "-71.063611".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::geo::Longitude;
+    /// assert_eq!("75.267150", rng.gen::<Longitude>().to_string());
+    /// assert_eq!("-12.268180", rng.gen::<Longitude>().to_string());
+    /// # for _ in 0..10000 {
+    /// #     let s = rng.gen::<Longitude>().to_string();
+    /// #     assert_eq!(6, s.split_once('.').unwrap().1.len(), "{}", s);
+    /// #     assert!((-180.0..=180.0).contains(&s.parse::<f64>().unwrap()), "{}", s);
+    /// # }
+    /// ```
+    pub struct Longitude(String);
 
-            "{} {}", FirstName, LastName;
-            "{} {} {}", NamePrefix, FirstName, LastName;
-            "{} {} {}", FirstName, LastName, NameSuffix;
-            "{} {} {} {}", NamePrefix, FirstName, LastName, NameSuffix;
+    impl Distribution<Longitude> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Longitude {
+            Longitude(format!("{:.6}", rng.gen_range(-180.0..=180.0)))
         }
     }
 
-    /// Generators for postal addresses and their constituent parts (e.g. city
-    /// names, postal codes, etc.).
-    pub mod addresses {
-        use super::names::{FirstName, FullName, LastName};
-        use crate::util::AsciiDigit;
+    __faker_impl_newtype!([] Longitude);
 
-        struct CityPrefix(String);
-        faker_impl_from_file!(CityPrefix, "data/en_us/city_prefixes");
+    /// Generates a [`Latitude`] and a [`Longitude`], separated by a comma and a
+    /// space, such as "42.358056, -71.063611".
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// use faker_rand::geo::Coordinate;
+    /// assert_eq!("35.845784, -158.338380", rng.gen::<Coordinate>().to_string());
+    /// assert_eq!("14.410466, -30.681677", rng.gen::<Coordinate>().to_string());
+    /// ```
+    pub struct Coordinate(String);
+    faker_impl_from_templates! {
+        Coordinate;
 
-        struct CitySuffix(String);
-        faker_impl_from_file!(CitySuffix, "data/en_us/city_suffixes");
+        "{}, {}", Latitude, Longitude;
+    }
+}
 
-        /// Generates a city name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::CityName;
-        /// assert_eq!("Cletastad", rng.gen::<CityName>().to_string());
-        /// ```
-        pub struct CityName(String);
-        faker_impl_from_templates! {
-            CityName;
+/// Runtime selection of localized generators.
+///
+/// The localized generators in this crate, like [`en_us::names::FirstName`] or
+/// [`fr_fr::names::FirstName`], are distinct types, and so have to be chosen at
+/// compile time. This module lets you choose a locale at runtime instead, for
+/// instance based on a command-line flag.
+pub mod locale {
+    use crate::Generator;
+    use crate::{de_de, en_gb, en_us, es_es, fr_fr, ja_jp};
+    use rand::{Rng, RngCore};
+    use std::error::Error;
+    use std::fmt;
+    use std::str::FromStr;
 
-            "{} {}{}", CityPrefix, FirstName, CitySuffix;
-            "{} {}", CityPrefix, FirstName;
-            "{}{}", FirstName, CitySuffix;
-            "{}{}", LastName, CitySuffix;
-        }
+    /// A locale supported by this crate.
+    ///
+    /// Each method on `Locale` generates data using the corresponding
+    /// generator from that locale's module. For example,
+    /// [`Locale::first_name`] uses [`en_us::names::FirstName`] for
+    /// [`Locale::EnUs`], and [`fr_fr::names::FirstName`] for
+    /// [`Locale::FrFr`].
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use faker_rand::locale::Locale;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// let locale: Locale = "fr_fr".parse().unwrap();
+    /// assert_eq!(Locale::FrFr, locale);
+    /// assert_eq!("Mahaut", locale.first_name(&mut rng));
+    /// assert_eq!("Joseph", Locale::EnUs.first_name(&mut rng));
+    /// ```
+    ///
+    /// Methods for generators that exist only in some locales return an
+    /// [`Option`], which is [`None`] for locales that lack the generator. They
+    /// do not fall back to another locale:
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use faker_rand::locale::Locale;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!(
+    ///     Some("Business-focused intermediate applications".to_string()),
+    ///     Locale::EnUs.slogan(&mut rng)
+    /// );
+    /// assert_eq!(None, Locale::FrFr.slogan(&mut rng));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Locale {
+        /// English as spoken in the United States. See [`en_us`].
+        EnUs,
 
-        struct StreetSuffix(String);
-        faker_impl_from_file!(StreetSuffix, "data/en_us/street_suffixes");
+        /// French as spoken in France. See [`fr_fr`].
+        FrFr,
 
-        /// Generates a street name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::StreetName;
-        /// assert_eq!("Renner Mission", rng.gen::<StreetName>().to_string());
-        /// ```
-        pub struct StreetName(String);
-        faker_impl_from_templates! {
-            StreetName;
+        /// German as spoken in Germany. See [`de_de`].
+        DeDe,
 
-            "{} {}", FirstName, StreetSuffix;
-            "{} {}", LastName, StreetSuffix;
-        }
+        /// Spanish as spoken in Spain. See [`es_es`].
+        EsEs,
 
-        struct BuildingNumber(String);
-        faker_impl_from_templates! {
-            BuildingNumber;
+        /// Japanese as spoken in Japan. See [`ja_jp`].
+        JaJp,
 
-            "{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
-            "{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
-            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
-        }
+        /// English as spoken in the United Kingdom. See [`en_gb`].
+        EnGb,
+    }
 
-        /// Generates a street address.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::StreetAddress;
-        /// assert_eq!("5489 Shanie Springs", rng.gen::<StreetAddress>().to_string());
-        /// ```
-        pub struct StreetAddress(String);
-        faker_impl_from_templates! {
-            StreetAddress;
+    type GenerateFn = fn(&mut dyn RngCore) -> String;
 
-            "{} {}", BuildingNumber, StreetName;
-        }
+    /// The generators for a locale. Generators that the locale doesn't have
+    /// are `None`.
+    struct Generators {
+        first_name: GenerateFn,
+        last_name: GenerateFn,
+        name_prefix: Option<GenerateFn>,
+        name_suffix: Option<GenerateFn>,
+        full_name: GenerateFn,
+        city_name: GenerateFn,
+        street_name: GenerateFn,
+        street_address: GenerateFn,
+        secondary_address: GenerateFn,
+        division: GenerateFn,
+        division_abbreviation: Option<GenerateFn>,
+        postal_code: GenerateFn,
+        address: GenerateFn,
+        company_name: GenerateFn,
+        slogan: Option<GenerateFn>,
+        domain: GenerateFn,
+        username: GenerateFn,
+        email: GenerateFn,
+        phone_number: GenerateFn,
+    }
 
-        /// Generates a secondary address (e.g. an apartment number).
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::SecondaryAddress;
-        /// assert_eq!("Suite 755", rng.gen::<SecondaryAddress>().to_string());
-        /// ```
-        pub struct SecondaryAddress(String);
-        faker_impl_from_templates! {
-            SecondaryAddress;
+    const EN_US: Generators = Generators {
+        first_name: |rng| en_us::names::FirstName::generate(rng),
+        last_name: |rng| en_us::names::LastName::generate(rng),
+        name_prefix: Some(|rng| en_us::names::NamePrefix::generate(rng)),
+        name_suffix: Some(|rng| en_us::names::NameSuffix::generate(rng)),
+        full_name: |rng| en_us::names::FullName::generate(rng),
+        city_name: |rng| en_us::addresses::CityName::generate(rng),
+        street_name: |rng| en_us::addresses::StreetName::generate(rng),
+        street_address: |rng| en_us::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| en_us::addresses::SecondaryAddress::generate(rng),
+        division: |rng| en_us::addresses::Division::generate(rng),
+        division_abbreviation: Some(|rng| en_us::addresses::DivisionAbbreviation::generate(rng)),
+        postal_code: |rng| en_us::addresses::PostalCode::generate(rng),
+        address: |rng| en_us::addresses::Address::generate(rng),
+        company_name: |rng| en_us::company::CompanyName::generate(rng),
+        slogan: Some(|rng| en_us::company::Slogan::generate(rng)),
+        domain: |rng| en_us::internet::Domain::generate(rng),
+        username: |rng| en_us::internet::Username::generate(rng),
+        email: |rng| en_us::internet::Email::generate(rng),
+        phone_number: |rng| en_us::phones::PhoneNumber::generate(rng),
+    };
 
-            "Apt. {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
-            "Suite {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
-        }
+    const FR_FR: Generators = Generators {
+        first_name: |rng| fr_fr::names::FirstName::generate(rng),
+        last_name: |rng| fr_fr::names::LastName::generate(rng),
+        name_prefix: Some(|rng| fr_fr::names::NamePrefix::generate(rng)),
+        name_suffix: None,
+        full_name: |rng| fr_fr::names::FullName::generate(rng),
+        city_name: |rng| fr_fr::addresses::CityName::generate(rng),
+        street_name: |rng| fr_fr::addresses::StreetName::generate(rng),
+        street_address: |rng| fr_fr::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| fr_fr::addresses::SecondaryAddress::generate(rng),
+        division: |rng| fr_fr::addresses::Division::generate(rng),
+        division_abbreviation: None,
+        postal_code: |rng| fr_fr::addresses::PostalCode::generate(rng),
+        address: |rng| fr_fr::addresses::Address::generate(rng),
+        company_name: |rng| fr_fr::company::CompanyName::generate(rng),
+        slogan: None,
+        domain: |rng| fr_fr::internet::Domain::generate(rng),
+        username: |rng| fr_fr::internet::Username::generate(rng),
+        email: |rng| fr_fr::internet::Email::generate(rng),
+        phone_number: |rng| fr_fr::phones::PhoneNumber::generate(rng),
+    };
 
-        /// Generates a first-level administrative division (e.g. one of the 50
-        /// states).
-        ///
-        /// Currently, other top-level divisions in USA, such as the District of
-        /// Columbia or the unincorporated organized territories (e.g. Puerto
-        /// Rico), are not included in this list. This may be changed in a
-        /// future minor version of this crate.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::Division;
-        /// assert_eq!("Oklahoma", rng.gen::<Division>().to_string());
-        /// ```
-        pub struct Division(String);
-        faker_impl_from_file!(Division, "data/en_us/divisions");
+    const DE_DE: Generators = Generators {
+        first_name: |rng| de_de::names::FirstName::generate(rng),
+        last_name: |rng| de_de::names::LastName::generate(rng),
+        name_prefix: Some(|rng| de_de::names::NamePrefix::generate(rng)),
+        name_suffix: None,
+        full_name: |rng| de_de::names::FullName::generate(rng),
+        city_name: |rng| de_de::addresses::CityName::generate(rng),
+        street_name: |rng| de_de::addresses::StreetName::generate(rng),
+        street_address: |rng| de_de::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| de_de::addresses::SecondaryAddress::generate(rng),
+        division: |rng| de_de::addresses::Division::generate(rng),
+        division_abbreviation: None,
+        postal_code: |rng| de_de::addresses::PostalCode::generate(rng),
+        address: |rng| de_de::addresses::Address::generate(rng),
+        company_name: |rng| de_de::company::CompanyName::generate(rng),
+        slogan: None,
+        domain: |rng| de_de::internet::Domain::generate(rng),
+        username: |rng| de_de::internet::Username::generate(rng),
+        email: |rng| de_de::internet::Email::generate(rng),
+        phone_number: |rng| de_de::phones::PhoneNumber::generate(rng),
+    };
 
-        /// Generates an abbreviated first-level division (e.g. the two-letter
-        /// abbreviation for one of the 50 states).
-        ///
-        /// See note in [`Division`] on the inclusion of entities other than the
-        /// 50 states, and how this may change in a minor version of this crate.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::DivisionAbbreviation;
-        /// assert_eq!("OK", rng.gen::<DivisionAbbreviation>().to_string());
-        /// ```
-        pub struct DivisionAbbreviation(String);
-        faker_impl_from_file!(DivisionAbbreviation, "data/en_us/division_abbreviations");
+    const ES_ES: Generators = Generators {
+        first_name: |rng| es_es::names::FirstName::generate(rng),
+        last_name: |rng| es_es::names::LastName::generate(rng),
+        name_prefix: Some(|rng| es_es::names::NamePrefix::generate(rng)),
+        name_suffix: None,
+        full_name: |rng| es_es::names::FullName::generate(rng),
+        city_name: |rng| es_es::addresses::CityName::generate(rng),
+        street_name: |rng| es_es::addresses::StreetName::generate(rng),
+        street_address: |rng| es_es::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| es_es::addresses::SecondaryAddress::generate(rng),
+        division: |rng| es_es::addresses::Division::generate(rng),
+        division_abbreviation: None,
+        postal_code: |rng| es_es::addresses::PostalCode::generate(rng),
+        address: |rng| es_es::addresses::Address::generate(rng),
+        company_name: |rng| es_es::company::CompanyName::generate(rng),
+        slogan: None,
+        domain: |rng| es_es::internet::Domain::generate(rng),
+        username: |rng| es_es::internet::Username::generate(rng),
+        email: |rng| es_es::internet::Email::generate(rng),
+        phone_number: |rng| es_es::phones::PhoneNumber::generate(rng),
+    };
 
-        /// Generates a postal code (a.k.a. a ZIP Code).
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::PostalCode;
-        /// assert_eq!("75548-9960", rng.gen::<PostalCode>().to_string());
-        /// ```
-        pub struct PostalCode(String);
-        faker_impl_from_templates! {
-            PostalCode;
+    const JA_JP: Generators = Generators {
+        first_name: |rng| ja_jp::names::FirstName::generate(rng),
+        last_name: |rng| ja_jp::names::LastName::generate(rng),
+        name_prefix: None,
+        name_suffix: None,
+        full_name: |rng| ja_jp::names::FullName::generate(rng),
+        city_name: |rng| ja_jp::addresses::CityName::generate(rng),
+        street_name: |rng| ja_jp::addresses::StreetName::generate(rng),
+        street_address: |rng| ja_jp::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| ja_jp::addresses::SecondaryAddress::generate(rng),
+        division: |rng| ja_jp::addresses::Division::generate(rng),
+        division_abbreviation: None,
+        postal_code: |rng| ja_jp::addresses::PostalCode::generate(rng),
+        address: |rng| ja_jp::addresses::Address::generate(rng),
+        company_name: |rng| ja_jp::company::CompanyName::generate(rng),
+        slogan: None,
+        domain: |rng| ja_jp::internet::Domain::generate(rng),
+        username: |rng| ja_jp::internet::Username::generate(rng),
+        email: |rng| ja_jp::internet::Email::generate(rng),
+        phone_number: |rng| ja_jp::phones::PhoneNumber::generate(rng),
+    };
+
+    const EN_GB: Generators = Generators {
+        first_name: |rng| en_gb::names::FirstName::generate(rng),
+        last_name: |rng| en_gb::names::LastName::generate(rng),
+        name_prefix: Some(|rng| en_gb::names::NamePrefix::generate(rng)),
+        name_suffix: None,
+        full_name: |rng| en_gb::names::FullName::generate(rng),
+        city_name: |rng| en_gb::addresses::CityName::generate(rng),
+        street_name: |rng| en_gb::addresses::StreetName::generate(rng),
+        street_address: |rng| en_gb::addresses::StreetAddress::generate(rng),
+        secondary_address: |rng| en_gb::addresses::SecondaryAddress::generate(rng),
+        division: |rng| en_gb::addresses::Division::generate(rng),
+        division_abbreviation: None,
+        postal_code: |rng| en_gb::addresses::PostalCode::generate(rng),
+        address: |rng| en_gb::addresses::Address::generate(rng),
+        company_name: |rng| en_gb::company::CompanyName::generate(rng),
+        slogan: None,
+        domain: |rng| en_gb::internet::Domain::generate(rng),
+        username: |rng| en_gb::internet::Username::generate(rng),
+        email: |rng| en_gb::internet::Email::generate(rng),
+        phone_number: |rng| en_gb::phones::PhoneNumber::generate(rng),
+    };
+
+    impl Locale {
+        /// Every locale supported by this crate.
+        pub const ALL: &'static [Locale] = &[
+            Locale::EnUs,
+            Locale::FrFr,
+            Locale::DeDe,
+            Locale::EsEs,
+            Locale::JaJp,
+            Locale::EnGb,
+        ];
 
-            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
-            "{}{}{}{}{}-{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        /// The generators for this locale. Supporting a new locale requires
+        /// only a new variant, its `Generators`, and an entry here and in
+        /// [`Locale::name`].
+        fn generators(self) -> &'static Generators {
+            match self {
+                Locale::EnUs => &EN_US,
+                Locale::FrFr => &FR_FR,
+                Locale::DeDe => &DE_DE,
+                Locale::EsEs => &ES_ES,
+                Locale::JaJp => &JA_JP,
+                Locale::EnGb => &EN_GB,
+            }
         }
 
-        /// Generates a full postal address.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::addresses::Address;
-        /// assert_eq!(
-        ///     "Cleta McClure III\n15364 Marks Passage Apt. 057\nMargaritaborough, MA 91404\n",
-        ///     rng.gen::<Address>().to_string()
-        /// );
-        /// ```
-        pub struct Address(String);
-        faker_impl_from_templates! {
-            Address;
-
-            "{}\n{}\n{}, {} {}\n", FullName, StreetAddress, CityName, DivisionAbbreviation, PostalCode;
-            "{}\n{} {}\n{}, {} {}\n", FullName, StreetAddress, SecondaryAddress, CityName, DivisionAbbreviation, PostalCode;
+        /// The name of this locale's module in this crate, e.g. `"en_us"`.
+        pub fn name(self) -> &'static str {
+            match self {
+                Locale::EnUs => "en_us",
+                Locale::FrFr => "fr_fr",
+                Locale::DeDe => "de_de",
+                Locale::EsEs => "es_es",
+                Locale::JaJp => "ja_jp",
+                Locale::EnGb => "en_gb",
+            }
         }
     }
 
-    /// Generators for company names and slogans.
-    pub mod company {
-        use super::names::{FirstName, LastName};
+    macro_rules! locale_methods {
+        ($($(#[$attr: meta])* $method: ident;)*) => {
+            impl Locale {
+                $(
+                    $(#[$attr])*
+                    pub fn $method<R: Rng>(self, rng: &mut R) -> String {
+                        (self.generators().$method)(rng)
+                    }
+                )*
+            }
+        };
+    }
 
-        struct CompanySuffix(String);
-        faker_impl_from_file!(CompanySuffix, "data/en_us/company_suffixes");
+    macro_rules! optional_locale_methods {
+        ($($(#[$attr: meta])* $method: ident;)*) => {
+            impl Locale {
+                $(
+                    $(#[$attr])*
+                    pub fn $method<R: Rng>(self, rng: &mut R) -> Option<String> {
+                        self.generators().$method.map(|generate| generate(rng))
+                    }
+                )*
+            }
+        };
+    }
 
-        /// Generates a company name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::company::CompanyName;
-        /// assert_eq!("Konopelski, Price, and Beier", rng.gen::<CompanyName>().to_string());
-        /// ```
-        pub struct CompanyName(String);
-        faker_impl_from_templates! {
-            CompanyName;
+    locale_methods! {
+        /// Generates a first name.
+        first_name;
 
-            "{} {}", FirstName, CompanySuffix;
-            "{}-{}", LastName, LastName;
-            "{}, {}, and {}", LastName, LastName, LastName;
-        }
+        /// Generates a last name.
+        last_name;
 
-        struct SloganAdjective(String);
-        faker_impl_from_file!(SloganAdjective, "data/en_us/slogan_adjectives");
+        /// Generates a full name.
+        full_name;
 
-        struct SloganDescriptor(String);
-        faker_impl_from_file!(SloganDescriptor, "data/en_us/slogan_descriptors");
+        /// Generates a city name.
+        city_name;
 
-        struct SloganNouns(String);
-        faker_impl_from_file!(SloganNouns, "data/en_us/slogan_nouns");
+        /// Generates a street name.
+        street_name;
 
-        /// Generates a company slogan.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::company::Slogan;
-        /// assert_eq!("Business-focused intermediate applications", rng.gen::<Slogan>().to_string());
-        /// ```
-        pub struct Slogan(String);
-        faker_impl_from_templates! {
-            Slogan;
+        /// Generates a street address.
+        street_address;
 
-            "{} {} {}", SloganAdjective, SloganDescriptor, SloganNouns;
-        }
-    }
+        /// Generates a secondary address (e.g. an apartment number).
+        secondary_address;
 
-    /// Generators for internet domain names, usernames, and emails.
-    pub mod internet {
-        use super::names::{FirstName, LastName};
-        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
+        /// Generates a first-level administrative division.
+        division;
 
-        struct DomainWord(String);
-        faker_impl_from_templates! {
-            DomainWord;
+        /// Generates a postal code.
+        postal_code;
 
-            "{}", ToAsciiLowercase<LastName>;
-        }
+        /// Generates a full postal address.
+        address;
 
-        struct DomainTLD(String);
-        faker_impl_from_file!(DomainTLD, "data/en_us/domain_tlds");
+        /// Generates a company name.
+        company_name;
 
         /// Generates a domain name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::internet::Domain;
-        /// assert_eq!("thiel.name", rng.gen::<Domain>().to_string());
-        /// ```
-        pub struct Domain(String);
-        faker_impl_from_templates! {
-            Domain;
-
-            "{}.{}", DomainWord, DomainTLD;
-        }
+        domain;
 
         /// Generates a username.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::internet::Username;
-        /// assert_eq!("odietrich48", rng.gen::<Username>().to_string());
-        /// ```
-        pub struct Username(String);
-        faker_impl_from_templates! {
-            Username;
-
-            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
-            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
-            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
-            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
-        }
+        username;
 
         /// Generates an email.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::internet::Email;
-        /// assert_eq!("odietrich48@thompson.net", rng.gen::<Email>().to_string());
-        /// ```
-        pub struct Email(String);
-        faker_impl_from_templates! {
-            Email;
+        email;
 
-            "{}@{}", Username, Domain;
-        }
+        /// Generates a phone number.
+        phone_number;
     }
 
-    /// Generators for phone numbers.
-    pub mod phones {
-        use crate::util::AsciiDigit;
+    optional_locale_methods! {
+        /// Generates a name prefix, if the locale has them.
+        name_prefix;
 
-        /// Generates a phone number.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::en_us::phones::PhoneNumber;
-        /// assert_eq!("(058) 981-5364", rng.gen::<PhoneNumber>().to_string());
-        /// ```
-        pub struct PhoneNumber(String);
-        faker_impl_from_templates! {
-            PhoneNumber;
+        /// Generates a name suffix, if the locale has them.
+        name_suffix;
+
+        /// Generates an abbreviated first-level division, if the locale has
+        /// them.
+        division_abbreviation;
 
-            "({}{}{}) {}{}{}-{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+        /// Generates a company slogan, if the locale has them.
+        slogan;
+    }
+
+    impl fmt::Display for Locale {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.name())
         }
     }
-}
 
-/// Localized generators for French as spoken in France (`fr-FR`).
-pub mod fr_fr {
-    /// Generators for the names of individuals (e.g., first, last, or full
-    /// names).
-    pub mod names {
-        /// Generates a first name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::names::FirstName;
-        /// assert_eq!("Mahaut", rng.gen::<FirstName>().to_string());
-        /// ```
-        pub struct FirstName(String);
-        faker_impl_from_file!(FirstName, "data/fr_fr/first_names");
+    /// The error returned when parsing an unsupported [`Locale`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ParseLocaleError(String);
 
-        /// Generates a last name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::names::LastName;
-        /// assert_eq!("GUILLOT", rng.gen::<LastName>().to_string());
-        /// ```
-        pub struct LastName(String);
-        faker_impl_from_file!(LastName, "data/fr_fr/last_names");
+    impl fmt::Display for ParseLocaleError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "unsupported locale: {:?}", self.0)
+        }
+    }
 
-        /// Generates a name prefix.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::names::NamePrefix;
-        /// assert_eq!("Dr", rng.gen::<NamePrefix>().to_string());
-        /// ```
-        pub struct NamePrefix(String);
-        faker_impl_from_file!(NamePrefix, "data/fr_fr/name_prefixes");
+    impl Error for ParseLocaleError {}
 
-        /// Generates a full name, including possibly a prefix.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::names::FullName;
-        /// assert_eq!("Mlle Gisèle MARTINEZ", rng.gen::<FullName>().to_string());
-        /// ```
-        pub struct FullName(String);
-        faker_impl_from_templates! {
-            FullName;
+    impl FromStr for Locale {
+        type Err = ParseLocaleError;
 
-            "{} {}", FirstName, LastName;
-            "{} {} {}", NamePrefix, FirstName, LastName;
+        /// Parses a locale from its module name (e.g. `"en_us"`), or from its
+        /// language tag (e.g. `"en-US"`). Parsing is case-insensitive.
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let name = s.to_ascii_lowercase().replace('-', "_");
+
+            Locale::ALL
+                .iter()
+                .copied()
+                .find(|locale| locale.name() == name)
+                .ok_or_else(|| ParseLocaleError(s.to_string()))
         }
     }
+}
 
-    /// Generators for postal addresses and their constituent parts (e.g. city
-    /// names, postal codes, etc.).
-    pub mod addresses {
-        use super::names::FullName;
-        use crate::util::AsciiDigit;
+/// Helpers for generating many values at once.
+pub mod bulk {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+    use std::marker::PhantomData;
 
-        /// Generates a city name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::addresses::CityName;
-        /// assert_eq!("Levallois-Perret", rng.gen::<CityName>().to_string());
-        /// ```
-        pub struct CityName(String);
-        faker_impl_from_file!(CityName, "data/fr_fr/city_names");
+    /// Generates `n` values using the generator `T`.
+    ///
+    /// The returned [`Vec`] is allocated up front, with room for exactly `n`
+    /// values, so it's never reallocated while the values are generated.
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use faker_rand::bulk::sample_strings;
+    /// use faker_rand::en_us::names::FirstName;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// let names = sample_strings::<FirstName, _>(&mut rng, 3);
+    /// assert_eq!(vec!["Michael", "Joseph", "Sarah"], names);
+    /// assert_eq!(3, names.capacity());
+    /// ```
+    ///
+    /// Apart from the [`Vec`] and the values themselves, nothing is allocated
+    /// per value unless `T` allocates while generating it. For example, a
+    /// generator that picks from a word file, like
+    /// [`FirstName`](crate::en_us::names::FirstName), only allocates the
+    /// [`String`] it's converted into:
+    ///
+    /// ```
+    /// use std::alloc::{GlobalAlloc, Layout, System};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// struct CountingAllocator;
+    ///
+    /// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// unsafe impl GlobalAlloc for CountingAllocator {
+    ///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ///         ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+    ///         System.alloc(layout)
+    ///     }
+    ///
+    ///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    ///         System.dealloc(ptr, layout)
+    ///     }
+    /// }
+    ///
+    /// #[global_allocator]
+    /// static GLOBAL: CountingAllocator = CountingAllocator;
+    ///
+    /// fn main() {
+    ///     use rand::SeedableRng;
+    ///     use faker_rand::bulk::sample_strings;
+    ///     use faker_rand::en_us::names::FirstName;
+    ///
+    ///     let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    ///     // Load the word file before counting allocations.
+    ///     sample_strings::<FirstName, _>(&mut rng, 1);
+    ///
+    ///     let before = ALLOCATIONS.load(Ordering::SeqCst);
+    ///     let names = sample_strings::<FirstName, _>(&mut rng, 1000);
+    ///     let allocations = ALLOCATIONS.load(Ordering::SeqCst) - before;
+    ///
+    ///     // One allocation for the Vec, and one for each name.
+    ///     assert_eq!(1000, names.len());
+    ///     assert_eq!(1 + 1000, allocations);
+    /// }
+    /// ```
+    pub fn sample_strings<T, R>(rng: &mut R, n: usize) -> Vec<String>
+    where
+        T: Into<String>,
+        Standard: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        let mut values = Vec::with_capacity(n);
+        for _ in 0..n {
+            values.push(rng.gen::<T>().into());
+        }
 
-        struct StreetPrefix(String);
-        faker_impl_from_file!(StreetPrefix, "data/fr_fr/street_prefixes");
+        values
+    }
+
+    /// Returns an endless iterator of values generated using the generator
+    /// `T`.
+    ///
+    /// This is like [`sample_strings`], except that values are generated
+    /// lazily rather than collected into a [`Vec`].
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use faker_rand::bulk::sample_iter_strings;
+    /// use faker_rand::en_us::names::FirstName;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// let mut names = sample_iter_strings::<FirstName, _>(&mut rng);
+    /// assert_eq!(Some("Michael".to_string()), names.next());
+    /// assert_eq!(Some("Joseph".to_string()), names.next());
+    /// assert_eq!(Some("Sarah".to_string()), names.next());
+    ///
+    /// // The iterator generates the same values as sample_strings would.
+    /// use faker_rand::bulk::sample_strings;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// let names: Vec<_> = sample_iter_strings::<FirstName, _>(&mut rng).take(1000).collect();
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// assert_eq!(sample_strings::<FirstName, _>(&mut rng, 1000), names);
+    /// ```
+    pub fn sample_iter_strings<T, R>(rng: &mut R) -> impl Iterator<Item = String> + '_
+    where
+        T: Into<String>,
+        Standard: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        std::iter::repeat_with(move || rng.gen::<T>().into())
+    }
 
-        struct StreetSuffix(String);
-        faker_impl_from_file!(StreetSuffix, "data/fr_fr/street_suffixes");
+    /// Extension methods for generating values straight from an RNG.
+    ///
+    /// This trait is implemented for every [`Rng`], so bringing it into scope
+    /// is enough to use its methods.
+    ///
+    /// ```
+    /// use rand::SeedableRng;
+    /// use faker_rand::bulk::FakerExt;
+    /// use faker_rand::en_us::names::FirstName;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("Michael", rng.fake::<FirstName>());
+    ///
+    /// let names: Vec<_> = rng.fake_iter::<FirstName>(3).collect();
+    /// assert_eq!(vec!["Joseph", "Sarah", "Keenan"], names);
+    ///
+    /// // fake_iter generates the same values as sample_strings would.
+    /// use faker_rand::bulk::sample_strings;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// let names: Vec<_> = rng.fake_iter::<FirstName>(10).collect();
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// assert_eq!(sample_strings::<FirstName, _>(&mut rng, 10), names);
+    /// ```
+    pub trait FakerExt: Rng {
+        /// Generates a value using the generator `T`.
+        fn fake<T>(&mut self) -> String
+        where
+            T: Into<String>,
+            Standard: Distribution<T>,
+        {
+            self.gen::<T>().into()
+        }
 
-        /// Generates a street name.
+        /// Returns an iterator of `n` values generated using the generator `T`.
         ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::addresses::StreetName;
-        /// assert_eq!("Passage de Seine", rng.gen::<StreetName>().to_string());
-        /// ```
-        pub struct StreetName(String);
-        faker_impl_from_templates! {
-            StreetName;
-
-            "{} {}", StreetPrefix, StreetSuffix;
+        /// This is like [`sample_iter_strings`], except that the iterator stops
+        /// after `n` values.
+        fn fake_iter<T>(&mut self, n: usize) -> std::iter::Take<FakeIter<'_, T, Self>>
+        where
+            T: Into<String>,
+            Standard: Distribution<T>,
+        {
+            FakeIter {
+                rng: self,
+                _generator: PhantomData,
+            }
+            .take(n)
         }
+    }
 
-        struct BuildingNumber(String);
-        faker_impl_from_templates! {
-            BuildingNumber;
+    impl<R: Rng + ?Sized> FakerExt for R {}
 
-            "{}", AsciiDigit;
-            "{}{}", AsciiDigit, AsciiDigit;
-            "{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
-        }
+    /// The iterator returned by [`FakerExt::fake_iter`].
+    pub struct FakeIter<'a, T, R: ?Sized> {
+        rng: &'a mut R,
+        _generator: PhantomData<T>,
+    }
 
-        /// Generates a street address.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::addresses::StreetAddress;
-        /// assert_eq!("54 Place de Montmorency", rng.gen::<StreetAddress>().to_string());
-        /// ```
-        pub struct StreetAddress(String);
-        faker_impl_from_templates! {
-            StreetAddress;
+    impl<T, R> Iterator for FakeIter<'_, T, R>
+    where
+        T: Into<String>,
+        Standard: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        type Item = String;
 
-            "{} {}", BuildingNumber, StreetName;
+        fn next(&mut self) -> Option<String> {
+            Some(self.rng.gen::<T>().into())
         }
+    }
+}
 
-        /// Generates a secondary address (e.g. an apartment number).
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::addresses::SecondaryAddress;
-        /// assert_eq!("7 étage", rng.gen::<SecondaryAddress>().to_string());
-        /// ```
-        pub struct SecondaryAddress(String);
-        faker_impl_from_templates! {
-            SecondaryAddress;
+/// Generators backed by word lists that are loaded at runtime.
+///
+/// The macros in this crate bake their word lists into your program at compile
+/// time. When the list isn't known until your program runs, for instance
+/// because it's supplied by your users, load it into a [`WordList`] instead.
+pub mod runtime {
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+    use std::fmt;
+    use std::fs::File;
+    use std::io::{self, BufRead, BufReader};
+    use std::marker::PhantomData;
+    use std::path::Path;
 
-            "Apt. {}{}{}", AsciiDigit, AsciiDigit, AsciiDigit;
-            "{} étage", AsciiDigit;
-        }
+    /// A list of words loaded at runtime, from which words are chosen
+    /// uniformly at random.
+    ///
+    /// Each line of the input is one word. Lines may end in either `\n` or
+    /// `\r\n`. Blank lines and lines starting with `#` are skipped, just as
+    /// they are by [`faker_impl_from_file`].
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// use faker_rand::runtime::WordList;
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// let colors = WordList::from_reader("red\ngreen\r\n\nblue\n".as_bytes()).unwrap();
+    /// assert_eq!("blue", colors.sample(&mut rng));
+    ///
+    /// // WordList is a Distribution, so it can also be used with rand's methods.
+    /// assert_eq!("green", rng.sample(&colors));
+    /// ```
+    ///
+    /// Lines are not parsed for weights the way [`faker_impl_from_file`] parses
+    /// them. For input without weights, a `WordList` picks words the same way
+    /// [`faker_impl_from_file`] does, so the same input and the same RNG produce
+    /// the same words either way:
+    ///
+    /// ```
+    /// use rand::{Rng, SeedableRng};
+    /// use faker_rand::lorem::Word;
+    /// use faker_rand::runtime::WordList;
+    ///
+    /// let words = WordList::from_path("src/data/lorem_words").unwrap();
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// let from_list: Vec<_> = (0..100).map(|_| words.sample(&mut rng).to_string()).collect();
+    ///
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    /// let from_file: Vec<_> = (0..100).map(|_| rng.gen::<Word>().to_string()).collect();
+    ///
+    /// assert_eq!(from_file, from_list);
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct WordList {
+        words: Vec<String>,
+    }
 
-        /// Generates a first-level administrative division (e.g. one of the
-        /// *régions* of France).
+    impl WordList {
+        /// Reads a word list, one word per line, from `reader`.
         ///
-        /// Currently, this will generate only one of the 13 metropolitan
-        /// regions of France. This may be changed in a future minor version of
-        /// this crate.
+        /// Returns [`Error::Empty`] if `reader` doesn't contain any words.
         ///
         /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        /// use faker_rand::runtime::{Error, WordList};
         ///
-        /// use faker_rand::fr_fr::addresses::Division;
-        /// assert_eq!("Nouvelle-Aquitaine", rng.gen::<Division>().to_string());
+        /// let err = WordList::from_reader("\n\r\n".as_bytes()).unwrap_err();
+        /// assert!(matches!(err, Error::Empty));
         /// ```
-        pub struct Division(String);
-        faker_impl_from_file!(Division, "data/fr_fr/divisions");
-
-        /// Generates a postal code.
-        ///
-        /// No guarantee is made that the first two digits correspond to a
-        /// correct department.
         ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        /// Comment lines and whitespace-only lines are not words:
         ///
-        /// use faker_rand::fr_fr::addresses::PostalCode;
-        /// assert_eq!("05898", rng.gen::<PostalCode>().to_string());
         /// ```
-        pub struct PostalCode(String);
-        faker_impl_from_templates! {
-            PostalCode;
-
-            "{}{}{}{}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
-        }
-
-        /// Generates a full postal address.
+        /// use rand::SeedableRng;
+        /// use faker_rand::runtime::{Error, WordList};
         ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
         /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
         ///
-        /// use faker_rand::fr_fr::addresses::Address;
-        /// assert_eq!(
-        ///     "Mlle Lucille MOREAU\nApt. 489\n96 Quai Saint-Jacques\n05764 Saint-Nazaire\nFRANCE\n",
-        ///     rng.gen::<Address>().to_string()
-        /// );
+        /// let words = WordList::from_reader("# fruits\napple\n  \n".as_bytes()).unwrap();
+        /// assert_eq!("apple", words.sample(&mut rng));
+        ///
+        /// let err = WordList::from_reader("# just a comment\n \t \n".as_bytes()).unwrap_err();
+        /// assert!(matches!(err, Error::Empty));
         /// ```
-        pub struct Address(String);
-        faker_impl_from_templates! {
-            Address;
-
-            "{}\n{}\n{} {}\nFRANCE\n", FullName, StreetAddress, PostalCode, CityName;
-            "{}\n{}\n{}\n{} {}\nFRANCE\n", FullName, SecondaryAddress, StreetAddress, PostalCode, CityName;
-        }
-    }
+        pub fn from_reader<B: BufRead>(reader: B) -> Result<Self, Error> {
+            let mut words = Vec::new();
+            for line in reader.lines() {
+                let line = line?;
+                if let Some(word) = crate::data_line(&line) {
+                    words.push(word.to_string());
+                }
+            }
 
-    /// Generators for company names.
-    pub mod company {
-        use super::names::FirstName;
+            if words.is_empty() {
+                return Err(Error::Empty);
+            }
 
-        struct CompanySuffix(String);
-        faker_impl_from_file!(CompanySuffix, "data/fr_fr/company_suffixes");
+            Ok(WordList { words })
+        }
 
-        /// Generates a company name.
+        /// Reads a word list, one word per line, from the file at `path`.
         ///
         /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+        /// use faker_rand::runtime::{Error, WordList};
         ///
-        /// use faker_rand::fr_fr::company::CompanyName;
-        /// assert_eq!("Lucille SARL", rng.gen::<CompanyName>().to_string());
+        /// let err = WordList::from_path("does/not/exist").unwrap_err();
+        /// assert!(matches!(err, Error::Io(_)));
         /// ```
-        pub struct CompanyName(String);
-        faker_impl_from_templates! {
-            CompanyName;
+        pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+            Self::from_reader(BufReader::new(File::open(path)?))
+        }
 
-            "{} {}", FirstName, CompanySuffix;
+        /// Chooses a word from the list.
+        pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &str {
+            &self.words[rng.gen_range(0..self.words.len())]
         }
     }
 
-    /// Generators for internet domain names, usernames, and emails.
-    pub mod internet {
-        use super::names::{FirstName, LastName};
-        use crate::util::{AsciiDigit, AsciiLowercase, ToAsciiLowercase};
-
-        struct DomainWord(String);
-        faker_impl_from_templates! {
-            DomainWord;
-
-            "{}", ToAsciiLowercase<LastName>;
+    impl<'a> Distribution<&'a str> for &'a WordList {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &'a str {
+            WordList::sample(self, rng)
         }
+    }
 
-        struct DomainTLD(String);
-        faker_impl_from_file!(DomainTLD, "data/fr_fr/domain_tlds");
+    /// The error returned when a [`WordList`] can't be loaded.
+    #[derive(Debug)]
+    pub enum Error {
+        /// The input couldn't be read.
+        Io(io::Error),
 
-        /// Generates a domain name.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::internet::Domain;
-        /// assert_eq!("renard.net", rng.gen::<Domain>().to_string());
-        /// ```
-        pub struct Domain(String);
-        faker_impl_from_templates! {
-            Domain;
+        /// The input didn't contain any words.
+        Empty,
+    }
 
-            "{}.{}", DomainWord, DomainTLD;
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::Io(err) => write!(f, "error reading word list: {}", err),
+                Error::Empty => write!(f, "word list is empty"),
+            }
         }
+    }
 
-        /// Generates a username.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::internet::Username;
-        /// assert_eq!("omartinez48", rng.gen::<Username>().to_string());
-        /// ```
-        pub struct Username(String);
-        faker_impl_from_templates! {
-            Username;
-
-            "{}{}", AsciiLowercase, ToAsciiLowercase<LastName>;
-            "{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit;
-            "{}{}{}{}", AsciiLowercase, ToAsciiLowercase<LastName>, AsciiDigit, AsciiDigit;
-            "{}{}", ToAsciiLowercase<FirstName>, ToAsciiLowercase<LastName>;
+    impl std::error::Error for Error {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                Error::Io(err) => Some(err),
+                Error::Empty => None,
+            }
         }
+    }
 
-        /// Generates an email.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::internet::Email;
-        /// assert_eq!("omartinez48@poirier.net", rng.gen::<Email>().to_string());
-        /// ```
-        pub struct Email(String);
-        faker_impl_from_templates! {
-            Email;
-
-            "{}@{}", Username, Domain;
+    impl From<io::Error> for Error {
+        fn from(err: io::Error) -> Self {
+            Error::Io(err)
         }
     }
 
-    /// Generators for phone numbers.
-    pub mod phones {
-        use crate::util::AsciiDigit;
+    /// A [`WordList`] that lives for the rest of the program, so that it can be
+    /// used as a generator through [`FromWordList`].
+    pub trait WordListSource {
+        /// Returns the word list.
+        fn word_list() -> &'static WordList;
+    }
 
-        /// Generates a phone number.
-        ///
-        /// ```
-        /// use rand::{Rng, SeedableRng};
-        /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
-        ///
-        /// use faker_rand::fr_fr::phones::PhoneNumber;
-        /// assert_eq!("00 58 98 15 36", rng.gen::<PhoneNumber>().to_string());
-        /// ```
-        pub struct PhoneNumber(String);
-        faker_impl_from_templates! {
-            PhoneNumber;
+    /// Generates a word from the [`WordList`] of `L`.
+    ///
+    /// This lets a word list loaded at runtime be used just like any other
+    /// generator, including inside templates:
+    ///
+    /// ```
+    /// use faker_rand::faker_impl_from_templates;
+    /// use faker_rand::runtime::{FromWordList, WordList, WordListSource};
+    /// use faker_rand::util::NumberInRange;
+    ///
+    /// use std::sync::OnceLock;
+    ///
+    /// struct Products;
+    /// impl WordListSource for Products {
+    ///     fn word_list() -> &'static WordList {
+    ///         static PRODUCTS: OnceLock<WordList> = OnceLock::new();
+    ///         PRODUCTS.get_or_init(|| {
+    ///             WordList::from_reader("Widget\nGadget\nGizmo\n".as_bytes()).unwrap()
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// struct Order(String);
+    /// faker_impl_from_templates! {
+    ///     Order;
+    ///
+    ///     "{} x {}", NumberInRange<1, 9>, FromWordList<Products>;
+    /// }
+    ///
+    /// use rand::{Rng, SeedableRng};
+    /// let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(0);
+    ///
+    /// assert_eq!("Gizmo", rng.gen::<FromWordList<Products>>().to_string());
+    /// assert_eq!("7 x Widget", rng.gen::<Order>().to_string());
+    /// ```
+    pub struct FromWordList<L>(String, PhantomData<L>);
 
-            "0{} {}{} {}{} {}{} {}{}", AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit, AsciiDigit;
+    impl<L: WordListSource> Distribution<FromWordList<L>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FromWordList<L> {
+            FromWordList(L::word_list().sample(rng).to_string(), PhantomData)
         }
     }
+
+    __faker_impl_newtype!([L] FromWordList<L>, PhantomData);
 }